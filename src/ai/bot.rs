@@ -0,0 +1,26 @@
+use super::Strategy;
+use crate::{game::World, physics::Direction};
+
+/// A `Bot` drives one of the `World`'s snakes with a `Strategy`.
+#[derive(Debug)]
+pub struct Bot {
+    snake:    usize,
+    strategy: Box<dyn Strategy>,
+}
+
+impl Bot {
+    /// Creates a new `Bot` driving `snake` (an index in `World::snakes`).
+    pub fn new(snake: usize, strategy: Box<dyn Strategy>) -> Self {
+        Self { snake, strategy }
+    }
+
+    /// Returns the index of the driven snake.
+    pub fn snake(&self) -> usize {
+        self.snake
+    }
+
+    /// Asks the `Strategy` for the next `Direction`.
+    pub fn direction(&mut self, world: &World) -> Direction {
+        self.strategy.direction(world, self.snake)
+    }
+}
@@ -0,0 +1,23 @@
+use super::{Greedy, ShortestPath, Strategy, Survival};
+
+/// The `Difficulty` of an AI opponent.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Difficulty {
+    /// Runs straight for the food.
+    Easy,
+    /// Takes the shortest path to the food, around bodies.
+    Normal,
+    /// Takes the shortest path to the food, but only when it is safe.
+    Hard,
+}
+
+impl Difficulty {
+    /// Returns the `Strategy` for this `Difficulty`.
+    pub fn strategy(self) -> Box<dyn Strategy> {
+        match self {
+            Self::Easy => Box::new(Greedy),
+            Self::Normal => Box::new(ShortestPath),
+            Self::Hard => Box::new(Survival),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use super::{candidates, Grid, Strategy};
+use crate::{game::World, physics::Direction};
+
+/// A `Greedy` `Strategy` heads straight to the food, only avoiding the very
+/// next cell when it is blocked.
+#[derive(Copy, Clone, Debug)]
+pub struct Greedy;
+
+impl Strategy for Greedy {
+    fn direction(&mut self, world: &World, snake: usize) -> Direction {
+        let snake = &world.snakes()[snake];
+        let grid = Grid::new(world);
+        let head = snake.head();
+        let food = world.food();
+
+        candidates(snake.direction())
+            .min_by_key(|direction| {
+                let next = grid.next(head, *direction);
+
+                (!grid.is_free(next), grid.distance(next, food))
+            })
+            .unwrap_or_else(|| snake.direction())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{Config, Level, Snake, SpeedCurve},
+        physics::{Point, Speed2D},
+    };
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    fn world() -> World {
+        let level = Level {
+            name:      "Test",
+            opponents: &[],
+            obstacles: &[],
+            speed:     SpeedCurve::constant(10.0),
+            goal:      None,
+        };
+        World::new(
+            Config::new((10.0, 10.0), Duration::from_millis(100)),
+            0,
+            &level,
+        )
+    }
+
+    #[test]
+    fn heads_to_food() {
+        let mut world = world();
+        world.place_food(Point::new(2.0, 1.0));
+
+        assert_eq!(Greedy.direction(&world, 0), Direction::Up);
+    }
+
+    #[test]
+    fn avoids_bodies() {
+        let mut world = world();
+        world.place_food(Point::new(9.0, 5.0));
+        world.add_snake(Snake::new(
+            (3.0, 5.0),
+            Direction::Up,
+            Speed2D::from_per_sec((1.0, 1.0)),
+        ));
+
+        assert_ne!(Greedy.direction(&world, 0), Direction::Right);
+    }
+}
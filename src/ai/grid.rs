@@ -0,0 +1,194 @@
+use crate::{
+    game::World,
+    physics::{Direction, Point},
+};
+use std::collections::VecDeque;
+
+/// A `Grid` of the `World`'s cells, telling which ones are blocked.
+#[derive(Clone, Debug)]
+pub struct Grid {
+    width:   usize,
+    height:  usize,
+    blocked: Vec<bool>,
+    portals: Vec<(Point, Point)>,
+}
+
+impl Grid {
+    /// Creates a new `Grid` from the `World`, where obstacles and snake bodies
+    /// are blocked.
+    pub fn new(world: &World) -> Self {
+        let bounds = world.bounds();
+        let width = bounds.x as usize;
+        let height = bounds.y as usize;
+        let mut grid = Self {
+            width,
+            height,
+            blocked: vec![false; width * height],
+            portals: world
+                .terrain()
+                .portals()
+                .iter()
+                .map(|portal| (portal.cell, portal.link))
+                .collect(),
+        };
+
+        for (cell, _) in world.terrain().obstacles() {
+            grid.block(cell);
+        }
+
+        for snake in world.snakes().iter().filter(|snake| snake.is_alive()) {
+            for point in snake.body() {
+                grid.block(*point);
+            }
+        }
+
+        grid
+    }
+
+    /// Marks `cell` as blocked.
+    pub fn block(&mut self, cell: Point) {
+        let index = self.index(cell);
+        self.blocked[index] = true;
+    }
+
+    /// Returns whether `cell` is free.
+    pub fn is_free(&self, cell: Point) -> bool {
+        !self.blocked[self.index(cell)]
+    }
+
+    /// Returns the cell next to `cell` in `direction`, wrapping around the
+    /// edges and going through portals.
+    pub fn next(&self, cell: Point, direction: Direction) -> Point {
+        let next = (cell + Point::from(direction)).wrap(self.bounds());
+
+        self.portals
+            .iter()
+            .find(|(portal, _)| *portal == next)
+            .map_or(next, |(_, link)| *link)
+    }
+
+    /// Returns the number of moves from `from` to `to` on an empty grid.
+    pub fn distance(&self, from: Point, to: Point) -> usize {
+        let axis = |from: f64, to: f64, size: usize| {
+            let delta = (from - to).abs() as usize;
+            delta.min(size - delta)
+        };
+
+        axis(from.x, to.x, self.width) + axis(from.y, to.y, self.height)
+    }
+
+    /// Returns the number of moves from `from` to every cell, going through
+    /// free cells only (`from` itself may be blocked).
+    pub fn distances(&self, from: Point) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.blocked.len()];
+        let mut queue = VecDeque::new();
+
+        distances[self.index(from)] = Some(0);
+        queue.push_back(from);
+
+        while let Some(cell) = queue.pop_front() {
+            let distance = distances[self.index(cell)].expect("Queued cells are reached");
+
+            for direction in Direction::ALL.iter() {
+                let next = self.next(cell, *direction);
+                let index = self.index(next);
+
+                if !self.blocked[index] && distances[index].is_none() {
+                    distances[index] = Some(distance + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Returns the number of free cells reachable from `from` (included).
+    pub fn area(&self, from: Point) -> usize {
+        if !self.is_free(from) {
+            return 0;
+        }
+
+        self.distances(from)
+            .iter()
+            .filter(|distance| distance.is_some())
+            .count()
+    }
+
+    /// Returns the index of `cell` in the grid.
+    pub fn index(&self, cell: Point) -> usize {
+        let cell = cell.wrap(self.bounds());
+
+        cell.y as usize * self.width + cell.x as usize
+    }
+
+    fn bounds(&self) -> Point {
+        Point::new(self.width as f64, self.height as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn grid() -> Grid {
+        // . . . .
+        // # # # .
+        // . . . .
+        let mut grid = Grid {
+            width:   4,
+            height:  3,
+            blocked: vec![false; 12],
+            portals: vec![],
+        };
+        grid.block(Point::new(0.0, 1.0));
+        grid.block(Point::new(1.0, 1.0));
+        grid.block(Point::new(2.0, 1.0));
+
+        grid
+    }
+
+    #[test]
+    fn next() {
+        let grid = grid();
+
+        assert_eq!(
+            grid.next(Point::new(3.0, 0.0), Direction::Right),
+            Point::new(0.0, 0.0)
+        );
+        assert_eq!(
+            grid.next(Point::new(3.0, 0.0), Direction::Up),
+            Point::new(3.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn distance() {
+        let grid = grid();
+
+        assert_eq!(grid.distance(Point::new(0.0, 0.0), Point::new(3.0, 2.0)), 2);
+        assert_eq!(grid.distance(Point::new(0.0, 0.0), Point::new(2.0, 1.0)), 3);
+    }
+
+    #[test]
+    fn distances() {
+        let mut grid = grid();
+        grid.block(Point::new(3.0, 1.0));
+
+        let distances = grid.distances(Point::new(0.0, 0.0));
+
+        assert_eq!(distances[grid.index(Point::new(1.0, 0.0))], Some(1));
+        // Through the top/bottom edges
+        assert_eq!(distances[grid.index(Point::new(1.0, 2.0))], Some(2));
+        assert_eq!(distances[grid.index(Point::new(1.0, 1.0))], None);
+    }
+
+    #[test]
+    fn area() {
+        let grid = grid();
+
+        assert_eq!(grid.area(Point::new(0.0, 0.0)), 9);
+        assert_eq!(grid.area(Point::new(0.0, 1.0)), 0);
+    }
+}
@@ -0,0 +1,17 @@
+//! Computer-controlled snakes
+
+mod bot;
+mod difficulty;
+mod greedy;
+mod grid;
+mod shortest_path;
+mod strategy;
+mod survival;
+
+pub use bot::*;
+pub use difficulty::*;
+pub use greedy::*;
+pub use grid::*;
+pub use shortest_path::*;
+pub use strategy::*;
+pub use survival::*;
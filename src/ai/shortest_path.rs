@@ -0,0 +1,74 @@
+use super::{candidates, Greedy, Grid, Strategy};
+use crate::{game::World, physics::Direction};
+
+/// A `ShortestPath` `Strategy` follows the shortest path to the food, going
+/// around bodies.
+///
+/// Falls back to `Greedy` when the food cannot be reached.
+#[derive(Copy, Clone, Debug)]
+pub struct ShortestPath;
+
+impl Strategy for ShortestPath {
+    fn direction(&mut self, world: &World, index: usize) -> Direction {
+        let snake = &world.snakes()[index];
+        let grid = Grid::new(world);
+        let head = snake.head();
+        let distances = grid.distances(world.food());
+
+        candidates(snake.direction())
+            .filter_map(|direction| {
+                let next = grid.next(head, direction);
+
+                if grid.is_free(next) {
+                    distances[grid.index(next)].map(|distance| (distance, direction))
+                } else {
+                    None
+                }
+            })
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, direction)| direction)
+            .unwrap_or_else(|| Greedy.direction(world, index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{Config, Level, Snake, SpeedCurve},
+        physics::{Moving, Point, Speed2D},
+    };
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn goes_around_walls() {
+        let level = Level {
+            name:      "Test",
+            opponents: &[],
+            obstacles: &[],
+            speed:     SpeedCurve::constant(10.0),
+            goal:      None,
+        };
+        let mut world = World::new(
+            Config::new((12.0, 12.0), Duration::from_millis(100)),
+            0,
+            &level,
+        );
+        // The player is at (3, 6), going right. Wall it on the right, from
+        // (4, 3) to (4, 10), and put the food behind.
+        let mut wall = Snake::new(
+            (4.0, 10.0),
+            Direction::Up,
+            Speed2D::from_per_sec((1.0, 1.0)),
+        );
+        for _ in 0..7 {
+            wall.r#move(Duration::from_secs(1));
+            wall.eat();
+        }
+        world.add_snake(wall);
+        world.place_food(Point::new(6.0, 6.0));
+
+        assert_eq!(ShortestPath.direction(&world, 0), Direction::Up);
+    }
+}
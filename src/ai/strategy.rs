@@ -0,0 +1,17 @@
+use crate::{game::World, physics::Direction};
+use std::fmt::Debug;
+
+/// A `Strategy` steers a snake of the `World`.
+pub trait Strategy: Debug {
+    /// Returns the `Direction` the `snake` (an index in `World::snakes`)
+    /// should take for this tick.
+    fn direction(&mut self, world: &World, snake: usize) -> Direction;
+}
+
+/// Returns the `Direction`s a snake going `direction` may turn to.
+pub fn candidates(direction: Direction) -> impl Iterator<Item = Direction> {
+    Direction::ALL
+        .iter()
+        .cloned()
+        .filter(move |candidate| *candidate != -direction)
+}
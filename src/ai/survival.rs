@@ -0,0 +1,95 @@
+use super::{candidates, Greedy, Grid, Strategy};
+use crate::{game::World, physics::Direction};
+
+/// A cautious `Survival` `Strategy`: goes where there is the most room left,
+/// then as close to the food as possible.
+///
+/// Room is measured with a flood fill from the next cell, so that the snake
+/// does not dive into dead ends, even with the food at the bottom.
+#[derive(Copy, Clone, Debug)]
+pub struct Survival;
+
+impl Strategy for Survival {
+    fn direction(&mut self, world: &World, index: usize) -> Direction {
+        let snake = &world.snakes()[index];
+        let grid = Grid::new(world);
+        let head = snake.head();
+        let distances = grid.distances(world.food());
+
+        candidates(snake.direction())
+            .filter_map(|direction| {
+                let next = grid.next(head, direction);
+
+                if grid.is_free(next) {
+                    let area = grid.area(next);
+                    let distance = distances[grid.index(next)].unwrap_or(usize::MAX);
+
+                    Some((area, distance, direction))
+                } else {
+                    None
+                }
+            })
+            .max_by(|(area1, distance1, _), (area2, distance2, _)| {
+                area1.cmp(area2).then(distance2.cmp(distance1))
+            })
+            .map(|(_, _, direction)| direction)
+            .unwrap_or_else(|| Greedy.direction(world, index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::{Config, Level, Snake, SpeedCurve},
+        physics::{Moving, Point, Speed2D},
+    };
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn avoids_dead_ends() {
+        let level = Level {
+            name:      "Test",
+            opponents: &[],
+            obstacles: &[],
+            speed:     SpeedCurve::constant(10.0),
+            goal:      None,
+        };
+        let mut world = World::new(
+            Config::new((12.0, 12.0), Duration::from_millis(100)),
+            0,
+            &level,
+        );
+        // The player is at (3, 6), going right. Build a pocket above it,
+        // with the food inside:
+        //
+        //     # # # #
+        //     # . F #
+        //       @ # #
+        let mut wall = Snake::new(
+            (4.0, 6.0),
+            Direction::Right,
+            Speed2D::from_per_sec((1.0, 1.0)),
+        );
+        let moves = [
+            Direction::Right,
+            Direction::Up,
+            Direction::Up,
+            Direction::Left,
+            Direction::Left,
+            Direction::Left,
+            Direction::Down,
+        ];
+        for direction in moves.iter() {
+            wall.set_direction(*direction);
+            wall.commit_direction();
+            wall.r#move(Duration::from_secs(1));
+            wall.eat();
+        }
+        world.add_snake(wall);
+        world.place_food(Point::new(4.0, 5.0));
+
+        assert_eq!(Survival.direction(&world, 0), Direction::Down);
+    }
+}
@@ -0,0 +1,362 @@
+use super::Backend;
+use crossterm::style::{Color, Colored};
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, Write},
+    str,
+};
+use unicode_width::UnicodeWidthChar;
+
+/// The `Style` of a `Cell`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Style {
+    pub fg:      Option<Color>,
+    pub bg:      Option<Color>,
+    pub bold:    bool,
+    pub dim:     bool,
+    pub reverse: bool,
+}
+
+/// A `Cell` of a `MemoryBackend`, one column wide.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Cell {
+    /// What is drawn in the cell, empty on the second column of wide
+    /// characters.
+    pub symbol: String,
+    pub style:  Style,
+}
+
+/// The `MemoryBackend` records the output of the game in a grid of cells,
+/// interpreting the commands like a terminal would.
+#[derive(Clone, Debug)]
+pub struct MemoryBackend {
+    width:   u16,
+    height:  u16,
+    cells:   Vec<Cell>,
+    cursor:  (u16, u16),
+    style:   Style,
+    /// The start of a command or character split across writes.
+    pending: Vec<u8>,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            symbol: " ".to_string(),
+            style:  Style::default(),
+        }
+    }
+}
+
+impl MemoryBackend {
+    /// Creates a blank `MemoryBackend` of `width` × `height` cells.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::default(); usize::from(width) * usize::from(height)],
+            cursor: (0, 0),
+            style: Style::default(),
+            pending: vec![],
+        }
+    }
+
+    /// Returns the `Cell` at column `x` of row `y`, if on the grid.
+    pub fn cell(&self, x: u16, y: u16) -> Option<&Cell> {
+        if x < self.width && y < self.height {
+            self.cells
+                .get(usize::from(y) * usize::from(self.width) + usize::from(x))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the position of the cursor, column then row.
+    pub fn cursor(&self) -> (u16, u16) {
+        self.cursor
+    }
+
+    /// Returns the text of row `y`, without styles.
+    pub fn line(&self, y: u16) -> String {
+        (0..self.width)
+            .filter_map(|x| self.cell(x, y))
+            .map(|cell| &cell.symbol[..])
+            .collect()
+    }
+
+    /// Returns the text of all the rows, without styles.
+    pub fn lines(&self) -> Vec<String> {
+        (0..self.height).map(|y| self.line(y)).collect()
+    }
+
+    fn cell_mut(&mut self, x: u16, y: u16) -> Option<&mut Cell> {
+        if x < self.width && y < self.height {
+            self.cells
+                .get_mut(usize::from(y) * usize::from(self.width) + usize::from(x))
+        } else {
+            None
+        }
+    }
+
+    /// Blanks the cells of row `y` from column `from` to `to`, excluded.
+    fn clear(&mut self, y: u16, from: u16, to: u16) {
+        for x in from..to.min(self.width) {
+            if let Some(cell) = self.cell_mut(x, y) {
+                *cell = Cell::default();
+            }
+        }
+    }
+
+    /// Draws `c` at the cursor, moving it after.
+    fn print(&mut self, c: char) {
+        let (x, y) = self.cursor;
+        let style = self.style;
+
+        match c.width() {
+            // Combining characters belong to the previous cell
+            Some(0) | None =>
+                if let Some(cell) = x.checked_sub(1).and_then(|x| self.cell_mut(x, y)) {
+                    cell.symbol.push(c);
+                },
+            Some(width) => {
+                if let Some(cell) = self.cell_mut(x, y) {
+                    *cell = Cell {
+                        symbol: c.to_string(),
+                        style,
+                    };
+                }
+                for i in 1..width as u16 {
+                    if let Some(cell) = self.cell_mut(x + i, y) {
+                        *cell = Cell {
+                            symbol: String::new(),
+                            style,
+                        };
+                    }
+                }
+
+                self.cursor.0 = x.saturating_add(width as u16);
+            }
+        }
+    }
+
+    /// Runs the CSI command ending with `end`, with `params`.
+    fn csi(&mut self, params: &str, end: char) {
+        let (x, y) = self.cursor;
+        let number = |i: usize, default: u16| {
+            params
+                .split(';')
+                .nth(i)
+                .and_then(|param| param.parse().ok())
+                .unwrap_or(default)
+        };
+
+        match (end, params) {
+            ('H', _) | ('f', _) =>
+                self.cursor = (
+                    number(1, 1).saturating_sub(1),
+                    number(0, 1).saturating_sub(1),
+                ),
+            ('A', _) => self.cursor.1 = y.saturating_sub(number(0, 1)),
+            ('B', _) => self.cursor.1 = y.saturating_add(number(0, 1)),
+            ('C', _) => self.cursor.0 = x.saturating_add(number(0, 1)),
+            ('D', _) => self.cursor.0 = x.saturating_sub(number(0, 1)),
+            ('G', _) => self.cursor.0 = number(0, 1).saturating_sub(1),
+            ('J', "2") =>
+                for y in 0..self.height {
+                    self.clear(y, 0, self.width);
+                },
+            ('J', "1") => {
+                for y in 0..y {
+                    self.clear(y, 0, self.width);
+                }
+                self.clear(y, 0, x + 1);
+            }
+            ('J', _) => {
+                self.clear(y, x, self.width);
+                for y in y + 1..self.height {
+                    self.clear(y, 0, self.width);
+                }
+            }
+            ('K', "2") => self.clear(y, 0, self.width),
+            ('K', "1") => self.clear(y, 0, x + 1),
+            ('K', _) => self.clear(y, x, self.width),
+            ('m', _) => self.sgr(params),
+            // Cursor visibility, alternate screen...
+            _ => {}
+        }
+    }
+
+    /// Applies the Select Graphic Rendition `params`.
+    fn sgr(&mut self, params: &str) {
+        match Colored::parse_ansi(params) {
+            Some(Colored::ForegroundColor(color)) =>
+                self.style.fg = Some(color).filter(|color| *color != Color::Reset),
+            Some(Colored::BackgroundColor(color)) =>
+                self.style.bg = Some(color).filter(|color| *color != Color::Reset),
+            None =>
+                for param in params.split(';') {
+                    match param {
+                        "" | "0" => self.style = Style::default(),
+                        "1" => self.style.bold = true,
+                        "2" => self.style.dim = true,
+                        "7" => self.style.reverse = true,
+                        "22" => {
+                            self.style.bold = false;
+                            self.style.dim = false;
+                        }
+                        "27" => self.style.reverse = false,
+                        _ => {}
+                    }
+                },
+        }
+    }
+
+    /// Interprets `text`, returning how much of it was complete.
+    fn interpret(&mut self, text: &str) -> usize {
+        let mut chars = text.char_indices().peekable();
+
+        while let Some((start, c)) = chars.next() {
+            match c {
+                '\x1B' => match chars.next() {
+                    Some((_, '[')) => {
+                        let params_start = start + 2;
+                        match chars.find(|(_, c)| ('\x40'..='\x7E').contains(c)) {
+                            Some((end, c)) => self.csi(&text[params_start..end], c),
+                            None => return start,
+                        }
+                    }
+                    // Titles, up to the bell
+                    Some((_, ']')) =>
+                        if chars.find(|(_, c)| *c == '\x07').is_none() {
+                            return start;
+                        },
+                    Some(_) => {}
+                    None => return start,
+                },
+                // Raw mode: new lines do not return to the first column
+                '\n' => self.cursor.1 = self.cursor.1.saturating_add(1),
+                '\r' => self.cursor.0 = 0,
+                c => self.print(c),
+            }
+        }
+
+        text.len()
+    }
+}
+
+impl Write for MemoryBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pending.extend_from_slice(buf);
+
+        let pending = std::mem::take(&mut self.pending);
+        let valid = match str::from_utf8(&pending) {
+            Ok(text) => text,
+            Err(err) => str::from_utf8(&pending[..err.valid_up_to()]).unwrap_or_default(),
+        };
+        let done = self.interpret(valid);
+        self.pending = pending[done..].to_vec();
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Backend for MemoryBackend {
+    fn size(&self) -> io::Result<(u16, u16)> {
+        Ok((self.width, self.height))
+    }
+}
+
+impl Display for MemoryBackend {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for line in self.lines() {
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::{
+        cursor::MoveTo,
+        queue,
+        style::{Attribute, Print, SetAttribute, SetForegroundColor},
+        terminal::{Clear, ClearType},
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn print() {
+        let mut backend = MemoryBackend::new(6, 2);
+        queue!(backend, MoveTo(1, 1), Print("ab\ncd")).unwrap();
+
+        assert_eq!(backend.lines(), vec!["      ", " ab   "]);
+        assert_eq!(backend.cursor(), (5, 2));
+    }
+
+    #[test]
+    fn wide() {
+        let mut backend = MemoryBackend::new(6, 1);
+        queue!(backend, Print("🐍a🦀")).unwrap();
+
+        assert_eq!(backend.line(0), "🐍a🦀 ");
+        assert_eq!(backend.cell(1, 0).unwrap().symbol, "");
+        assert_eq!(backend.cursor(), (5, 0));
+    }
+
+    #[test]
+    fn clear() {
+        let mut backend = MemoryBackend::new(4, 2);
+        queue!(
+            backend,
+            Print("abcd"),
+            MoveTo(0, 1),
+            Print("efgh"),
+            MoveTo(2, 0),
+            Clear(ClearType::UntilNewLine),
+        )
+        .unwrap();
+        assert_eq!(backend.lines(), vec!["ab  ", "efgh"]);
+
+        queue!(backend, Clear(ClearType::All)).unwrap();
+        assert_eq!(backend.lines(), vec!["    ", "    "]);
+    }
+
+    #[test]
+    fn styles() {
+        let mut backend = MemoryBackend::new(3, 1);
+        queue!(
+            backend,
+            SetAttribute(Attribute::Bold),
+            SetForegroundColor(Color::Rgb { r: 1, g: 2, b: 3 }),
+            Print("a"),
+            SetAttribute(Attribute::Reset),
+            Print("b"),
+        )
+        .unwrap();
+
+        assert_eq!(backend.cell(0, 0).unwrap().style, Style {
+            fg: Some(Color::Rgb { r: 1, g: 2, b: 3 }),
+            bold: true,
+            ..Style::default()
+        });
+        assert_eq!(backend.cell(1, 0).unwrap().style, Style::default());
+    }
+
+    #[test]
+    fn split_writes() {
+        let mut backend = MemoryBackend::new(3, 2);
+        let bytes = format!("{}🐍", MoveTo(1, 1)).into_bytes();
+        for byte in bytes {
+            backend.write_all(&[byte]).unwrap();
+        }
+
+        assert_eq!(backend.line(1), " 🐍");
+    }
+}
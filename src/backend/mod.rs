@@ -0,0 +1,31 @@
+//! Where screens render to
+//!
+//! Rendering queues crossterm commands on a `Backend`: a real terminal, or a
+//! grid of cells in memory to assert on frames or embed the game elsewhere.
+
+mod memory;
+mod terminal;
+
+pub use memory::*;
+pub use terminal::*;
+
+use std::{
+    fmt::Debug,
+    io::{self, Write},
+};
+
+/// A `Backend` receives the output of the game, as crossterm commands.
+pub trait Backend: Write + Debug {
+    /// Returns the size of the terminal, in columns and rows.
+    fn size(&self) -> io::Result<(u16, u16)>;
+
+    /// Prepares the terminal for the game.
+    fn enter(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Restores the terminal as it was before the game.
+    fn leave(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use super::Backend;
+use crate::consts::RAW_MODE;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, size};
+use std::io::{self, stdout, Stdout, Write};
+
+/// The `CrosstermBackend` renders to the real terminal, through stdout.
+#[derive(Debug)]
+pub struct CrosstermBackend {
+    out: Stdout,
+}
+
+impl CrosstermBackend {
+    pub fn new() -> Self {
+        Self { out: stdout() }
+    }
+}
+
+impl Default for CrosstermBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Write for CrosstermBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.out.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+impl Backend for CrosstermBackend {
+    fn size(&self) -> io::Result<(u16, u16)> {
+        size().map_err(io::Error::other)
+    }
+
+    fn enter(&mut self) -> io::Result<()> {
+        if RAW_MODE {
+            enable_raw_mode().map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+
+    fn leave(&mut self) -> io::Result<()> {
+        if RAW_MODE {
+            disable_raw_mode().map_err(io::Error::other)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,245 @@
+//! Command line options
+
+use crate::{
+    game::LEVELS,
+    theme::{Theme, THEMES},
+};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: snake [OPTIONS]
+
+Options:
+    --record <file>    Save a replay of the game to <file>
+    --replay <file>    Play the replay in <file> back
+    --stats-csv <file> Export the lifetime stats to <file> as CSV
+    --seed <n>         Play with the seed <n> instead of a random one
+    --theme <name>     Draw with the emoji, ascii or blocks theme
+    --diagonals        Move in eight directions, with the numpad or qweasdzxc
+    --two-players      Play with a friend on the same keyboard, on wasd
+    --relative <n>     Player <n> turns left/right with ←/→ (or a/d)
+    --server <addr>    Host a multiplayer game on <addr> (e.g. 0.0.0.0:7777)
+    --level <n>        Play level <n> on the server (default: 1)
+    --connect <addr>   Join the multiplayer game hosted on <addr>
+    --broadcast <addr> Stream the game to viewers on <addr> (or unix:<path>)
+    --watch <addr>     Watch the game streamed on <addr> (or unix:<path>)
+    -h, --help         Print this help";
+
+/// The command line `Options`.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Options {
+    /// Where to save a replay of the game.
+    pub record:       Option<PathBuf>,
+    /// The replay to play back, instead of playing.
+    pub replay:       Option<PathBuf>,
+    /// Where to export the lifetime stats, instead of playing.
+    pub export_stats: Option<PathBuf>,
+    /// The seed of the `World`, instead of a random one.
+    pub seed:         Option<u64>,
+    /// Where to host a multiplayer game.
+    pub server:       Option<String>,
+    /// The id of the level of the server.
+    pub level:        u8,
+    /// Where to join a multiplayer game.
+    pub connect:      Option<String>,
+    /// Where to stream the game to viewers.
+    pub broadcast:    Option<String>,
+    /// Where to watch a streamed game.
+    pub watch:        Option<String>,
+    /// The `Theme` to draw with.
+    pub theme:        Theme,
+    /// Whether to steer diagonally too.
+    pub diagonals:    bool,
+    /// Whether two players share the keyboard.
+    pub two_players:  bool,
+    /// Whether each player turns relative to its heading.
+    pub relative:     [bool; 2],
+}
+
+impl Options {
+    /// Parses the `Options` from `args`, without the program name.
+    ///
+    /// Returns `Ok(None)` when help is requested.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Option<Self>, String> {
+        let mut options = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("Missing value for {}", arg))
+            };
+
+            match arg.as_str() {
+                "--record" => options.record = Some(value()?.into()),
+                "--replay" => options.replay = Some(value()?.into()),
+                "--stats-csv" => options.export_stats = Some(value()?.into()),
+                "--server" => options.server = Some(value()?),
+                "--connect" => options.connect = Some(value()?),
+                "--broadcast" => options.broadcast = Some(value()?),
+                "--watch" => options.watch = Some(value()?),
+                "--level" => {
+                    let level = value()?;
+                    options.level = level
+                        .parse::<u8>()
+                        .ok()
+                        .filter(|level| (1..=LEVELS.len()).contains(&usize::from(*level)))
+                        .ok_or_else(|| format!("Invalid level {}", level))?
+                        - 1;
+                }
+                "--seed" => {
+                    let seed = value()?;
+                    options.seed =
+                        Some(seed.parse().map_err(|_| format!("Invalid seed {}", seed))?);
+                }
+                "--theme" => {
+                    let name = value()?;
+                    options.theme = Theme::get(&name).ok_or_else(|| {
+                        let names = THEMES.iter().map(|theme| theme.name).collect::<Vec<_>>();
+                        format!("Unknown theme {}, expected {}", name, names.join(", "))
+                    })?;
+                }
+                "--diagonals" => options.diagonals = true,
+                "--two-players" => options.two_players = true,
+                "--relative" => {
+                    let player = value()?;
+                    match player.as_str() {
+                        "1" => options.relative[0] = true,
+                        "2" => options.relative[1] = true,
+                        _ => return Err(format!("Invalid player {}", player)),
+                    }
+                }
+                "-h" | "--help" => return Ok(None),
+                _ => return Err(format!("Unknown option {}", arg)),
+            }
+        }
+
+        Ok(Some(options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::BLOCKS;
+    use pretty_assertions::assert_eq;
+
+    fn parse(args: &[&str]) -> Result<Option<Options>, String> {
+        Options::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parse_empty() {
+        assert_eq!(parse(&[]), Ok(Some(Options::default())));
+    }
+
+    #[test]
+    fn parse_paths() {
+        assert_eq!(
+            parse(&["--record", "a.snkr", "--replay", "b.snkr"]),
+            Ok(Some(Options {
+                record: Some("a.snkr".into()),
+                replay: Some("b.snkr".into()),
+                ..Options::default()
+            }))
+        );
+        assert_eq!(
+            parse(&["--stats-csv", "stats.csv"]),
+            Ok(Some(Options {
+                export_stats: Some("stats.csv".into()),
+                ..Options::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_seed() {
+        assert_eq!(
+            parse(&["--seed", "42"]),
+            Ok(Some(Options {
+                seed: Some(42),
+                ..Options::default()
+            }))
+        );
+        assert!(parse(&["--seed", "-1"]).is_err());
+    }
+
+    #[test]
+    fn parse_multiplayer() {
+        assert_eq!(
+            parse(&["--server", "0.0.0.0:7777", "--level", "2"]),
+            Ok(Some(Options {
+                server: Some("0.0.0.0:7777".into()),
+                level: 1,
+                ..Options::default()
+            }))
+        );
+        assert_eq!(
+            parse(&["--connect", "localhost:7777"]),
+            Ok(Some(Options {
+                connect: Some("localhost:7777".into()),
+                ..Options::default()
+            }))
+        );
+        assert_eq!(
+            parse(&["--broadcast", "unix:/tmp/snake.sock"]),
+            Ok(Some(Options {
+                broadcast: Some("unix:/tmp/snake.sock".into()),
+                ..Options::default()
+            }))
+        );
+        assert_eq!(
+            parse(&["--watch", "localhost:7778"]),
+            Ok(Some(Options {
+                watch: Some("localhost:7778".into()),
+                ..Options::default()
+            }))
+        );
+        assert!(parse(&["--level", "0"]).is_err());
+        assert!(parse(&["--level", "6"]).is_err());
+    }
+
+    #[test]
+    fn parse_diagonals() {
+        assert_eq!(
+            parse(&["--diagonals", "--seed", "1"]),
+            Ok(Some(Options {
+                diagonals: true,
+                seed: Some(1),
+                ..Options::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_theme() {
+        assert_eq!(
+            parse(&["--theme", "blocks"]),
+            Ok(Some(Options {
+                theme: BLOCKS,
+                ..Options::default()
+            }))
+        );
+        assert!(parse(&["--theme", "neon"]).is_err());
+    }
+
+    #[test]
+    fn parse_controls() {
+        assert_eq!(
+            parse(&["--two-players", "--relative", "2"]),
+            Ok(Some(Options {
+                two_players: true,
+                relative: [false, true],
+                ..Options::default()
+            }))
+        );
+        assert!(parse(&["--relative", "3"]).is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse(&["--help"]), Ok(None));
+        assert!(parse(&["--replay"]).is_err());
+        assert!(parse(&["--nope"]).is_err());
+    }
+}
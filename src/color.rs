@@ -0,0 +1,268 @@
+//! Colors, degraded to what the terminal supports
+//!
+//! Colors are defined in RGB, then drawn in truecolor, in the 256 colors
+//! palette or in the 16 basic colors depending on the `ColorDepth`.
+
+use crossterm::style::Color;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A color in RGB.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+/// The colors a terminal supports.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum ColorDepth {
+    /// The 16 basic colors.
+    Ansi16,
+    /// The 256 colors palette.
+    Ansi256,
+    /// Any RGB color.
+    TrueColor,
+}
+
+/// A `Gradient` between the colors of the head and of the tail of a snake.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Gradient {
+    pub head: Rgb,
+    pub tail: Rgb,
+}
+
+/// The `Palette` of the game.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Palette {
+    /// The snakes of the players.
+    pub player:         Gradient,
+    /// The snakes of the bots.
+    pub opponent:       Gradient,
+    pub food:           Rgb,
+    pub wall:           Rgb,
+    pub rock:           Rgb,
+    pub portal:         Rgb,
+    /// The background of the selected item of menus.
+    pub highlight:      Rgb,
+    /// The text of the selected item of menus.
+    pub highlight_text: Rgb,
+}
+
+pub const PALETTE: Palette = Palette {
+    player:         Gradient {
+        head: Rgb::new(140, 230, 60),
+        tail: Rgb::new(20, 110, 40),
+    },
+    opponent:       Gradient {
+        head: Rgb::new(240, 90, 60),
+        tail: Rgb::new(130, 30, 80),
+    },
+    food:           Rgb::new(250, 200, 40),
+    wall:           Rgb::new(170, 90, 50),
+    rock:           Rgb::new(140, 140, 140),
+    portal:         Rgb::new(120, 100, 250),
+    highlight:      Rgb::new(40, 120, 50),
+    highlight_text: Rgb::new(255, 255, 255),
+};
+
+/// The 16 basic colors, as xterm draws them.
+const ANSI16: [(Color, Rgb); 16] = [
+    (Color::Black, Rgb::new(0, 0, 0)),
+    (Color::DarkRed, Rgb::new(205, 0, 0)),
+    (Color::DarkGreen, Rgb::new(0, 205, 0)),
+    (Color::DarkYellow, Rgb::new(205, 205, 0)),
+    (Color::DarkBlue, Rgb::new(0, 0, 238)),
+    (Color::DarkMagenta, Rgb::new(205, 0, 205)),
+    (Color::DarkCyan, Rgb::new(0, 205, 205)),
+    (Color::Grey, Rgb::new(229, 229, 229)),
+    (Color::DarkGrey, Rgb::new(127, 127, 127)),
+    (Color::Red, Rgb::new(255, 0, 0)),
+    (Color::Green, Rgb::new(0, 255, 0)),
+    (Color::Yellow, Rgb::new(255, 255, 0)),
+    (Color::Blue, Rgb::new(92, 92, 255)),
+    (Color::Magenta, Rgb::new(255, 0, 255)),
+    (Color::Cyan, Rgb::new(0, 255, 255)),
+    (Color::White, Rgb::new(255, 255, 255)),
+];
+
+/// The levels of each component in the color cube of the 256 colors.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The `ColorDepth` colors are drawn with.
+static DEPTH: AtomicUsize = AtomicUsize::new(0);
+
+impl Rgb {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Returns the color `t` of the way from this one to `other`, `t` being
+    /// between 0 and 1.
+    pub fn mix(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+
+        Self::new(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+        )
+    }
+
+    /// Returns this color in the current `ColorDepth`.
+    pub fn color(self) -> Color {
+        self.with_depth(depth())
+    }
+
+    /// Returns the closest color to this one in `depth`.
+    pub fn with_depth(self, depth: ColorDepth) -> Color {
+        match depth {
+            ColorDepth::TrueColor => Color::Rgb {
+                r: self.r,
+                g: self.g,
+                b: self.b,
+            },
+            ColorDepth::Ansi256 => Color::AnsiValue(self.ansi256()),
+            ColorDepth::Ansi16 => ANSI16
+                .iter()
+                .min_by_key(|(_, rgb)| self.distance(*rgb))
+                .map(|(color, _)| *color)
+                .unwrap_or(Color::Reset),
+        }
+    }
+
+    /// Returns the closest color of the 256 colors palette, from its color
+    /// cube or its grayscale ramp.
+    fn ansi256(self) -> u8 {
+        let level = |c: u8| match c {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (c - 35) / 40,
+        };
+        let (r, g, b) = (level(self.r), level(self.g), level(self.b));
+        let cube = Self::new(
+            CUBE_LEVELS[usize::from(r)],
+            CUBE_LEVELS[usize::from(g)],
+            CUBE_LEVELS[usize::from(b)],
+        );
+
+        let average = (u16::from(self.r) + u16::from(self.g) + u16::from(self.b)) / 3;
+        let step = (average.saturating_sub(3) / 10).min(23) as u8;
+        let gray = Self::new(8 + step * 10, 8 + step * 10, 8 + step * 10);
+
+        if self.distance(gray) < self.distance(cube) {
+            232 + step
+        } else {
+            16 + 36 * r + 6 * g + b
+        }
+    }
+
+    /// Returns the squared distance between this color and `other`.
+    fn distance(self, other: Self) -> u32 {
+        let square = |a: u8, b: u8| (i32::from(a) - i32::from(b)).pow(2) as u32;
+
+        square(self.r, other.r) + square(self.g, other.g) + square(self.b, other.b)
+    }
+}
+
+impl ColorDepth {
+    const ALL: [ColorDepth; 3] = [Self::Ansi16, Self::Ansi256, Self::TrueColor];
+
+    /// Guesses the `ColorDepth` of the terminal from the environment.
+    pub fn detect() -> Self {
+        Self::from_env(
+            std::env::var("COLORTERM").ok().as_deref(),
+            std::env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    /// Returns the `ColorDepth` of a terminal with the given `COLORTERM` and
+    /// `TERM` variables.
+    fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        match (colorterm, term) {
+            (Some("truecolor"), _) | (Some("24bit"), _) => Self::TrueColor,
+            (_, Some(term)) if term.contains("256") => Self::Ansi256,
+            _ => Self::Ansi16,
+        }
+    }
+}
+
+impl Gradient {
+    /// Returns the color of the cell at `index` of a body of `len` cells,
+    /// head first.
+    pub fn at(&self, index: usize, len: usize) -> Rgb {
+        if len <= 1 {
+            self.head
+        } else {
+            self.head.mix(self.tail, index as f64 / (len - 1) as f64)
+        }
+    }
+}
+
+/// Returns the current `ColorDepth`.
+pub fn depth() -> ColorDepth {
+    ColorDepth::ALL[DEPTH.load(Ordering::Relaxed)]
+}
+
+/// Makes colors be drawn in `depth`.
+pub fn set_depth(depth: ColorDepth) {
+    if let Some(index) = ColorDepth::ALL.iter().position(|other| *other == depth) {
+        DEPTH.store(index, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn gradient() {
+        let gradient = Gradient {
+            head: Rgb::new(0, 100, 200),
+            tail: Rgb::new(100, 100, 0),
+        };
+
+        assert_eq!(gradient.at(0, 3), Rgb::new(0, 100, 200));
+        assert_eq!(gradient.at(1, 3), Rgb::new(50, 100, 100));
+        assert_eq!(gradient.at(2, 3), Rgb::new(100, 100, 0));
+        assert_eq!(gradient.at(0, 1), Rgb::new(0, 100, 200));
+    }
+
+    #[test]
+    fn fallback() {
+        let orange = Rgb::new(255, 135, 0);
+        assert_eq!(orange.with_depth(ColorDepth::TrueColor), Color::Rgb {
+            r: 255,
+            g: 135,
+            b: 0,
+        });
+        assert_eq!(
+            orange.with_depth(ColorDepth::Ansi256),
+            Color::AnsiValue(208)
+        );
+        assert_eq!(orange.with_depth(ColorDepth::Ansi16), Color::DarkYellow);
+
+        let gray = Rgb::new(100, 100, 100);
+        assert_eq!(gray.with_depth(ColorDepth::Ansi256), Color::AnsiValue(241));
+        assert_eq!(gray.with_depth(ColorDepth::Ansi16), Color::DarkGrey);
+    }
+
+    #[test]
+    fn detect() {
+        assert_eq!(
+            ColorDepth::from_env(Some("truecolor"), Some("xterm-256color")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::from_env(None, Some("xterm-256color")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            ColorDepth::from_env(None, Some("linux")),
+            ColorDepth::Ansi16
+        );
+        assert_eq!(ColorDepth::from_env(None, None), ColorDepth::Ansi16);
+    }
+}
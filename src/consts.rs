@@ -5,6 +5,23 @@ pub const TITLE: &str = "🐍🐍👅";
 
 pub const FPS: u64 = 5;
 
+/// Size of the multiplayer `World`, in cells
+pub const SERVER_WIDTH: u16 = 40;
+pub const SERVER_HEIGHT: u16 = 20;
+
+/// Size of the largest `World` the game supports, in cells
+pub const MAX_ARENA_WIDTH: u16 = 512;
+pub const MAX_ARENA_HEIGHT: u16 = 256;
+
+/// Points per food eaten
+pub const FOOD_SCORE: u32 = 10;
+
+/// How long toasts are shown, in game frames
+pub const TOAST_FRAMES: u64 = 3 * FPS;
+
+/// Playback speeds of replays
+pub const REPLAY_SPEEDS: [f64; 6] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+
 pub const WELCOME_FPS: u64 = 100;
 pub const MENU_FPS: u64 = 20;
 
@@ -18,6 +35,12 @@ pub const SNAKE_BLINK_TIME: u64 = millis_to_frames(200, WELCOME_FPS);
 /// After "snake" started blinking, in frames
 pub const CONTINUE_DELAY: u64 = millis_to_frames(1000, WELCOME_FPS);
 
+/// Without input after "snake" started blinking, in frames
+pub const DEMO_DELAY: u64 = millis_to_frames(10_000, WELCOME_FPS);
+
+/// Welcome frames per demo game update
+pub const DEMO_FRAMES_PER_UPDATE: u64 = WELCOME_FPS / FPS;
+
 pub const WELCOME: [&str; 11] = [
     r" __      __       .__                               ",
     r"/  \    /  \ ____ |  |   ____  ____   _____   ____  ",
@@ -31,7 +54,6 @@ pub const WELCOME: [&str; 11] = [
     r"                    |  | (  <_> )                   ",
     r"                    |__|  \____/                    ",
 ];
-pub const WELCOME_WIDTH: u16 = WELCOME[0].len() as u16;
 pub const WELCOME_HEIGHT: u16 = WELCOME.len() as u16;
 
 pub const SNAKE: [&str; 5] = [
@@ -41,16 +63,22 @@ pub const SNAKE: [&str; 5] = [
     r"    🐍  🐍  🐍🐍   🐍 🐍 🐍   🐍 🐍   🐍    ",
     r"🐍🐍🐍  🐍    🐍  🐍      👅  🐍  🐍  🐍🐍🐍",
 ];
-pub const SNAKE_WIDTH: u16 = SNAKE[0].len() as u16 - 19; // Adjust because weird chars
 pub const SNAKE_HEIGHT: u16 = SNAKE.len() as u16;
 
 pub const CONTINUE: [&str; 2] = [
     "       [Press ESC to quit]      ",
     "[Press any other key to continue]",
 ];
-pub const CONTINUE_WIDTH: u16 = CONTINUE[0].len() as u16;
 pub const CONTINUE_HEIGHT: u16 = CONTINUE.len() as u16;
 
-pub const KEYS: [&str; 9] = [
-    "[", "↲", " play ", "↑", " prev ", "↓", " next ", "ESC", " quit]",
+#[rustfmt::skip]
+pub const KEYS: [&str; 17] = [
+    "[", "↲", " play ",
+    "↑↓", " select ",
+    "←→", " mode ",
+    "A", " achievements ",
+    "S", " stats ",
+    "H", " scores ",
+    "O", " settings ",
+    "ESC", " quit]",
 ];
@@ -0,0 +1,100 @@
+use super::{key_direction, KeyCode};
+use crate::physics::Direction;
+
+/// The `Controls` of a player: which keys steer its snake, and how.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Controls {
+    /// Arrows, plus the diagonal keys if `diagonals` is on.
+    Arrows { diagonals: bool },
+    /// `w`, `a`, `s` and `d`.
+    Wasd,
+    /// Turns 90° counter-clockwise with `left` and clockwise with `right`,
+    /// relative to the current heading.
+    Relative { left: KeyCode, right: KeyCode },
+}
+
+impl Controls {
+    /// Returns the `Controls` of the `player` (0 or 1) sharing the keyboard
+    /// with `players` players.
+    ///
+    /// The first player uses the arrows, the second one `w`, `a`, `s` and
+    /// `d`, so the diagonal keys are only available to a lone player.
+    pub fn for_player(player: usize, players: usize, relative: bool, diagonals: bool) -> Self {
+        match (player, relative) {
+            (0, false) => Self::Arrows {
+                diagonals: diagonals && players == 1,
+            },
+            (0, true) => Self::Relative {
+                left:  KeyCode::Left,
+                right: KeyCode::Right,
+            },
+            (_, false) => Self::Wasd,
+            (_, true) => Self::Relative {
+                left:  KeyCode::Char('a'),
+                right: KeyCode::Char('d'),
+            },
+        }
+    }
+
+    /// Returns whether these `Controls` turn relative to the heading.
+    pub fn is_relative(&self) -> bool {
+        matches!(self, Self::Relative { .. })
+    }
+
+    /// Returns the `Direction` `code` steers to, for a snake going
+    /// `heading`.
+    pub fn direction(&self, code: KeyCode, heading: Direction) -> Option<Direction> {
+        match *self {
+            Self::Arrows { diagonals } => key_direction(code, diagonals),
+            Self::Wasd => match code {
+                KeyCode::Char('w') => Some(Direction::Up),
+                KeyCode::Char('a') => Some(Direction::Left),
+                KeyCode::Char('s') => Some(Direction::Down),
+                KeyCode::Char('d') => Some(Direction::Right),
+                _ => None,
+            },
+            Self::Relative { left, .. } if code == left => Some(heading.turn_left()),
+            Self::Relative { right, .. } if code == right => Some(heading.turn_right()),
+            Self::Relative { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn relative() {
+        let controls = Controls::for_player(1, 2, true, false);
+
+        assert_eq!(
+            controls.direction(KeyCode::Char('a'), Direction::Up),
+            Some(Direction::Left)
+        );
+        assert_eq!(
+            controls.direction(KeyCode::Char('d'), Direction::Up),
+            Some(Direction::Right)
+        );
+        assert_eq!(controls.direction(KeyCode::Char('w'), Direction::Up), None);
+        assert_eq!(controls.direction(KeyCode::Up, Direction::Up), None);
+    }
+
+    #[test]
+    fn two_players() {
+        let first = Controls::for_player(0, 2, false, true);
+        let second = Controls::for_player(1, 2, false, true);
+
+        assert_eq!(first, Controls::Arrows { diagonals: false });
+        assert_eq!(
+            first.direction(KeyCode::Up, Direction::Left),
+            Some(Direction::Up)
+        );
+        assert_eq!(first.direction(KeyCode::Char('w'), Direction::Left), None);
+        assert_eq!(
+            second.direction(KeyCode::Char('w'), Direction::Left),
+            Some(Direction::Up)
+        );
+    }
+}
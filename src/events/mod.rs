@@ -1,24 +1,87 @@
-use crossterm::event::{poll as xpoll, read};
-use std::time::Duration;
+mod controls;
+mod ticker;
 
+use crate::physics::Direction;
+use crossterm::event::{poll, read};
+use std::time::Instant;
+
+pub use controls::*;
 pub use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent};
+pub use ticker::*;
 
-pub fn poll() -> Option<Event> {
-    if let Ok(true) = xpoll(Duration::from_secs(0)) {
-        if let Ok(event) = read() {
-            return Some(event);
+/// Blocks until a key is pressed, and returns it.
+///
+/// Other events are skipped. `Esc` is returned when the terminal cannot be
+/// read, so that screens can still be left.
+pub fn read_key() -> KeyCode {
+    loop {
+        match read() {
+            Ok(Event::Key(KeyEvent { code, .. })) => return code,
+            Ok(_) => {}
+            Err(_) => return KeyCode::Esc,
         }
     }
+}
+
+/// Blocks until a key is pressed or `deadline` passes, and returns the key
+/// if any.
+///
+/// Other events are skipped.
+pub fn read_key_until(deadline: Instant) -> Option<KeyCode> {
+    loop {
+        let timeout = deadline.saturating_duration_since(Instant::now());
 
-    None
+        match poll(timeout) {
+            Ok(true) => match read() {
+                Ok(Event::Key(KeyEvent { code, .. })) => return Some(code),
+                Ok(_) => {}
+                Err(_) => return None,
+            },
+            _ => return None,
+        }
+    }
 }
 
-pub fn poll_all() -> Vec<Event> {
-    let mut events = vec![];
+/// Returns the `Direction` of an arrow key.
+pub fn arrow_direction(code: KeyCode) -> Option<Direction> {
+    match code {
+        KeyCode::Up => Some(Direction::Up),
+        KeyCode::Down => Some(Direction::Down),
+        KeyCode::Left => Some(Direction::Left),
+        KeyCode::Right => Some(Direction::Right),
+        _ => None,
+    }
+}
 
-    while let Some(event) = poll() {
-        events.push(event);
+/// Returns the `Direction` of a diagonal key, on the numpad or around `s`:
+///
+/// ```text
+/// 7 8 9    q w e
+/// 4   6    a   d
+/// 1 2 3    z x c
+/// ```
+pub fn diagonal_direction(code: KeyCode) -> Option<Direction> {
+    match code {
+        KeyCode::Char('7') | KeyCode::Char('q') => Some(Direction::UpLeft),
+        KeyCode::Char('8') | KeyCode::Char('w') => Some(Direction::Up),
+        KeyCode::Char('9') | KeyCode::Char('e') => Some(Direction::UpRight),
+        KeyCode::Char('4') | KeyCode::Char('a') => Some(Direction::Left),
+        KeyCode::Char('6') | KeyCode::Char('d') => Some(Direction::Right),
+        KeyCode::Char('1') | KeyCode::Char('z') => Some(Direction::DownLeft),
+        KeyCode::Char('2') | KeyCode::Char('x') => Some(Direction::Down),
+        KeyCode::Char('3') | KeyCode::Char('c') => Some(Direction::DownRight),
+        _ => None,
     }
+}
 
-    events
+/// Returns the `Direction` of a key: arrows, and diagonal keys if
+/// `diagonals` is on.
+pub fn key_direction(code: KeyCode, diagonals: bool) -> Option<Direction> {
+    arrow_direction(code).or_else(|| {
+        if diagonals {
+            diagonal_direction(code)
+        } else {
+            None
+        }
+    })
 }
@@ -0,0 +1,53 @@
+use std::time::{Duration, Instant};
+
+/// A `Ticker` schedules frames at a steady pace.
+///
+/// Each deadline follows the previous one rather than the end of the frame,
+/// so that frames do not drift however long they take.
+#[derive(Copy, Clone, Debug)]
+pub struct Ticker {
+    deadline: Instant,
+}
+
+impl Ticker {
+    /// Creates a new `Ticker`, the first frame due in `period`.
+    pub fn new(period: Duration) -> Self {
+        Self {
+            deadline: Instant::now() + period,
+        }
+    }
+
+    /// Returns when the current frame is due.
+    pub fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// Schedules the next frame `period` after the current one.
+    ///
+    /// When late, the next frame is due right away rather than catching up
+    /// with a burst of frames.
+    pub fn tick(&mut self, period: Duration) {
+        self.deadline = (self.deadline + period).max(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn steady() {
+        let period = Duration::from_secs(10);
+        let mut ticker = Ticker::new(period);
+        let first = ticker.deadline();
+
+        ticker.tick(period);
+        assert_eq!(ticker.deadline(), first + period);
+
+        ticker.deadline = Instant::now() - 3 * period;
+        ticker.tick(period);
+        assert!(ticker.deadline() <= Instant::now());
+        assert!(ticker.deadline() > Instant::now() - period);
+    }
+}
@@ -0,0 +1,335 @@
+use super::{GameMode, Level, World};
+use crate::physics::{Direction, Point};
+use std::{
+    collections::VecDeque,
+    fmt::{Display, Error, Formatter},
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+    time::Duration,
+};
+
+/// The file where achievements are saved, in the game's directory.
+const FILE: &str = "achievements";
+
+/// The level of `Achievement::RightMinded`.
+const SNAKE_DEN: &str = "Snake Den";
+
+/// The time window of `Achievement::Rush`.
+const RUSH_WINDOW: Duration = Duration::from_secs(2);
+
+/// An `Achievement` unlocks when its progress reaches its goal.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Achievement {
+    /// Reach a length of 50.
+    Length,
+    /// Eat 3 foods in 2 seconds.
+    Rush,
+    /// Finish Snake Den without turning left.
+    RightMinded,
+    /// Survive a minute in survival.
+    Survivor,
+}
+
+/// The progress of all the `Achievement`s, saved across games.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct Achievements {
+    progress: [u32; Achievement::ALL.len()],
+}
+
+/// A `Tracker` checks the `Achievement`s as a single player game is played.
+#[derive(Clone, Debug)]
+pub struct Tracker {
+    level:       u8,
+    mode:        GameMode,
+    eaten:       u32,
+    eats:        VecDeque<Duration>,
+    turned_left: bool,
+}
+
+impl Achievement {
+    /// All the `Achievement`s, as listed in the menu.
+    pub const ALL: [Achievement; 4] = [Self::Length, Self::Rush, Self::RightMinded, Self::Survivor];
+
+    /// Returns the key of this `Achievement` in the save file.
+    fn key(self) -> &'static str {
+        match self {
+            Self::Length => "length",
+            Self::Rush => "rush",
+            Self::RightMinded => "right-minded",
+            Self::Survivor => "survivor",
+        }
+    }
+
+    /// Returns the name of this `Achievement`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Length => "Long boi",
+            Self::Rush => "Sugar rush",
+            Self::RightMinded => "Right-minded",
+            Self::Survivor => "Survivor",
+        }
+    }
+
+    /// Returns what to do to unlock this `Achievement`.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Length => "Reach a length of 50",
+            Self::Rush => "Eat 3 foods in 2 seconds",
+            Self::RightMinded => "Finish Snake Den without turning left",
+            Self::Survivor => "Survive a minute in survival",
+        }
+    }
+
+    /// Returns the progress needed to unlock this `Achievement`.
+    pub fn goal(self) -> u32 {
+        match self {
+            Self::Length => 50,
+            Self::Rush => 3,
+            Self::RightMinded => Level::find(SNAKE_DEN)
+                .and_then(|id| Level::get(id).goal)
+                .expect("Snake Den has a goal"),
+            Self::Survivor => 60,
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|achievement| *achievement == self)
+            .expect("All achievements are listed")
+    }
+}
+
+impl Achievements {
+    /// Loads the `Achievements` saved in `dir`, none when never saved.
+    ///
+    /// Unknown or invalid lines are ignored.
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        match fs::read_to_string(dir.join(FILE)) {
+            Ok(text) => Ok(Self::parse(&text)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Saves the `Achievements` in `dir`.
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        fs::write(dir.join(FILE), self.to_string())
+    }
+
+    /// Returns the best progress of `achievement`, up to its goal.
+    pub fn progress(&self, achievement: Achievement) -> u32 {
+        self.progress[achievement.index()]
+    }
+
+    /// Returns whether `achievement` is unlocked.
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.progress(achievement) >= achievement.goal()
+    }
+
+    /// Records `progress` towards `achievement`.
+    ///
+    /// Returns whether it unlocked it.
+    pub fn advance(&mut self, achievement: Achievement, progress: u32) -> bool {
+        let was_unlocked = self.is_unlocked(achievement);
+        let best = &mut self.progress[achievement.index()];
+        *best = (*best).max(progress.min(achievement.goal()));
+
+        !was_unlocked && self.is_unlocked(achievement)
+    }
+
+    fn parse(text: &str) -> Self {
+        let mut achievements = Self::default();
+
+        for line in text.lines() {
+            let mut words = line.split_whitespace();
+            let achievement = words
+                .next()
+                .and_then(|key| Achievement::ALL.iter().find(|a| a.key() == key));
+            let progress = words.next().and_then(|progress| progress.parse().ok());
+
+            if let (Some(achievement), Some(progress)) = (achievement, progress) {
+                achievements.advance(*achievement, progress);
+            }
+        }
+
+        achievements
+    }
+}
+
+impl Display for Achievements {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        for achievement in Achievement::ALL.iter() {
+            writeln!(f, "{} {}", achievement.key(), self.progress(*achievement))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Tracker {
+    /// Creates a new `Tracker` for a game on the level with the given `id`, in
+    /// `mode`.
+    pub fn new(level: u8, mode: GameMode) -> Self {
+        Self {
+            level,
+            mode,
+            eaten: 0,
+            eats: VecDeque::new(),
+            turned_left: false,
+        }
+    }
+
+    /// Notes that the player steered from `heading` to `direction`.
+    pub fn steer(&mut self, heading: Direction, direction: Direction) {
+        let heading = Point::from(heading);
+        let direction = Point::from(direction);
+
+        // Rows grow downwards, so left turns are clockwise in maths
+        if heading.x * direction.y - heading.y * direction.x < 0.0 {
+            self.turned_left = true;
+        }
+    }
+
+    /// Checks the `Achievement`s after an update of `world`, recording the
+    /// progress in `achievements`.
+    ///
+    /// Returns the newly unlocked ones.
+    pub fn update(&mut self, world: &World, achievements: &mut Achievements) -> Vec<Achievement> {
+        let player = world.player();
+        let now = world.elapsed();
+
+        for _ in self.eaten..player.eaten() {
+            self.eats.push_back(now);
+        }
+        self.eaten = player.eaten();
+        while self
+            .eats
+            .front()
+            .is_some_and(|eat| now - *eat >= RUSH_WINDOW)
+        {
+            self.eats.pop_front();
+        }
+
+        let mut progress = vec![
+            (Achievement::Length, player.body().count() as u32),
+            (Achievement::Rush, self.eats.len() as u32),
+        ];
+        // Classic games of Snake Den are over when the score reaches its goal
+        let den = Level::find(SNAKE_DEN);
+        if den == Some(self.level) && self.mode == GameMode::Classic && !self.turned_left {
+            progress.push((Achievement::RightMinded, world.score()));
+        }
+        if self.mode == GameMode::Survival && player.is_alive() {
+            progress.push((Achievement::Survivor, now.as_secs() as u32));
+        }
+
+        progress
+            .into_iter()
+            .filter(|(achievement, progress)| achievements.advance(*achievement, *progress))
+            .map(|(achievement, _)| achievement)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Config, Level};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn advance() {
+        let mut achievements = Achievements::default();
+
+        assert!(!achievements.advance(Achievement::Rush, 2));
+        assert!(!achievements.advance(Achievement::Rush, 1));
+        assert_eq!(achievements.progress(Achievement::Rush), 2);
+        assert!(achievements.advance(Achievement::Rush, 5));
+        assert!(!achievements.advance(Achievement::Rush, 3));
+        assert_eq!(achievements.progress(Achievement::Rush), 3);
+        assert!(achievements.is_unlocked(Achievement::Rush));
+    }
+
+    #[test]
+    fn parse() {
+        let mut achievements = Achievements::default();
+        achievements.advance(Achievement::Length, 12);
+        achievements.advance(Achievement::Survivor, 60);
+
+        assert_eq!(Achievements::parse(&achievements.to_string()), achievements);
+        assert_eq!(
+            Achievements::parse("rush 2\nunknown 4\nlength many\n"),
+            Achievements {
+                progress: [0, 2, 0, 0],
+            }
+        );
+    }
+
+    #[test]
+    fn turning_left() {
+        let mut tracker = Tracker::new(1, GameMode::Classic);
+        tracker.steer(Direction::Right, Direction::Down);
+        tracker.steer(Direction::Up, Direction::UpRight);
+        tracker.steer(Direction::Up, Direction::Down);
+        assert!(!tracker.turned_left);
+
+        tracker.steer(Direction::Down, Direction::Right);
+        assert!(tracker.turned_left);
+    }
+
+    #[test]
+    fn right_minded() {
+        let den = Level::find(SNAKE_DEN).expect("Snake Den exists");
+        let config = Config::new((40.0, 20.0), Duration::from_millis(100));
+        let mut world = World::new(config, 42, Level::get(den));
+        let mut tracker = Tracker::new(den, GameMode::Classic);
+        let mut achievements = Achievements::default();
+
+        // Feed the player as it loops around clockwise, alone
+        world.kill(1);
+        let mut unlocked = vec![];
+        while !world.is_over() {
+            let heading = world.player().direction();
+            let head = world.player().head();
+            let turn = match heading {
+                Direction::Right => head.x >= 28.0,
+                Direction::Down => head.y >= 14.0,
+                Direction::Left => head.x <= 8.0,
+                _ => head.y <= 4.0,
+            };
+            if turn {
+                world.steer(heading.turn_right());
+                tracker.steer(heading, heading.turn_right());
+            }
+            world.place_food(head + Point::from(world.player().direction()));
+            world.update();
+            unlocked.extend(tracker.update(&world, &mut achievements));
+        }
+
+        assert!(world.is_finished());
+        assert!(unlocked.contains(&Achievement::RightMinded));
+    }
+
+    #[test]
+    fn update() {
+        let config = Config::new((20.0, 10.0), Duration::from_millis(100));
+        let mut world = World::new(config, 42, Level::get(1)).with_mode(GameMode::Survival);
+        let mut tracker = Tracker::new(1, GameMode::Survival);
+        let mut achievements = Achievements::default();
+
+        let mut unlocked = vec![];
+        for _ in 0..10 {
+            world.update();
+            unlocked.extend(tracker.update(&world, &mut achievements));
+        }
+
+        assert_eq!(unlocked, vec![]);
+        assert_eq!(achievements.progress(Achievement::Survivor), 1);
+        assert_eq!(
+            achievements.progress(Achievement::Length),
+            world.player().body().count() as u32
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use crate::physics::Point;
+use std::time::Duration;
+
+/// The `Config`uration of a `World`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Config {
+    /// The size of the `World`, in cells.
+    pub bounds: Point,
+    /// The simulated `Duration` of each update.
+    pub delta:  Duration,
+}
+
+impl Config {
+    /// Creates a new `Config`.
+    pub fn new(bounds: impl Into<Point>, delta: Duration) -> Self {
+        Self {
+            bounds: bounds.into(),
+            delta,
+        }
+    }
+}
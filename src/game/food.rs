@@ -1,6 +1,11 @@
-use crate::physics::{Duration, Moving, Path, PathFragment, Point};
-use crossterm::cursor::MoveTo;
-use std::fmt::{Display, Error, Formatter};
+use crate::{
+    color::PALETTE,
+    physics::{Duration, Moving, Path, PathFragment, Point},
+    theme::{Draw, Theme},
+    width,
+};
+use crossterm::style::{ResetColor, SetForegroundColor};
+use std::fmt::{Error, Formatter};
 
 #[derive(Debug)]
 pub struct Food<T: Iterator<Item = PathFragment>> {
@@ -19,6 +24,11 @@ impl<T: Iterator<Item = PathFragment>> Food<T> {
             path: Path::new(path),
         }
     }
+
+    /// Returns the cell the path starts from.
+    pub fn origin(&self) -> Point {
+        self.initial
+    }
 }
 
 impl<T: Iterator<Item = PathFragment>> Moving for Food<T> {
@@ -28,10 +38,17 @@ impl<T: Iterator<Item = PathFragment>> Moving for Food<T> {
     }
 }
 
-impl<T: Iterator<Item = PathFragment>> Display for Food<T> {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+impl<T: Iterator<Item = PathFragment>> Draw for Food<T> {
+    fn draw(&self, f: &mut Formatter, theme: &Theme) -> Result<(), Error> {
         let position = self.position.round();
 
-        write!(f, "{}🦀", MoveTo(position.x as u16 * 2, position.y as u16))
+        write!(
+            f,
+            "{}{}{}{}",
+            width::move_to(position),
+            SetForegroundColor(PALETTE.food.color()),
+            theme.food,
+            ResetColor
+        )
     }
 }
@@ -1,74 +1,392 @@
-use super::World;
-use crate::{
-    consts::FPS,
-    events::{poll_all, Event, KeyCode, KeyEvent},
-    screen::{MenuAction, Screen, WelcomeAction},
+use super::{
+    clock,
+    Achievements,
+    Config,
+    Ghost,
+    HighScore,
+    HighScores,
+    Layout,
+    Replay,
+    Scenes,
+    Snapshot,
+    Stats,
+    WelcomeScene,
 };
-use std::{
-    thread::sleep,
-    time::{Duration, Instant},
+use crate::{
+    cli::Options,
+    consts::{FPS, REPLAY_SPEEDS},
+    events::{read_key_until, Controls, KeyCode, Ticker},
+    net::{Broadcaster, Client, Endpoint, Watcher},
+    physics::Direction,
+    screen::Screen,
+    storage,
+    theme::Draw,
 };
+use crossterm::terminal::{Clear, ClearType};
+use std::{path::PathBuf, time::Duration};
 
 #[derive(Debug)]
 pub struct Game {
-    screen: Screen,
+    pub(super) screen:       Screen,
+    pub(super) options:      Options,
+    pub(super) broadcaster:  Option<Broadcaster>,
+    pub(super) achievements: Achievements,
+    pub(super) stats:        Stats,
+    pub(super) high_scores:  HighScores,
+    /// The directory the progress is loaded from and saved to, if any.
+    pub(super) data_dir:     Option<PathBuf>,
+    /// The errors to print when leaving the screen.
+    errors:                  Vec<String>,
 }
 
 impl Game {
-    pub fn new() -> Self {
-        let screen = Screen::new();
+    pub fn new(options: Options) -> Self {
+        let mut game = Self::with_screen(Screen::new(), options);
 
-        Self { screen }
+        match storage::dir() {
+            Ok(dir) => game.with_data_dir(dir),
+            Err(err) => {
+                game.errors.push(format!("Cannot save progress: {}", err));
+                game
+            }
+        }
+    }
+
+    /// Loads the progress saved in `dir`, where it will be saved.
+    pub fn with_data_dir(self, dir: PathBuf) -> Self {
+        Self {
+            achievements: Achievements::load(&dir).unwrap_or_default(),
+            stats: Stats::load(&dir).unwrap_or_default(),
+            high_scores: HighScores::load(&dir).unwrap_or_default(),
+            data_dir: Some(dir),
+            ..self
+        }
+    }
+
+    /// Creates a `Game` on `screen`, with nothing saved yet.
+    pub fn with_screen(screen: Screen, options: Options) -> Self {
+        Self {
+            screen,
+            options,
+            broadcaster: None,
+            achievements: Achievements::default(),
+            stats: Stats::default(),
+            high_scores: HighScores::default(),
+            data_dir: None,
+            errors: vec![],
+        }
     }
 
     pub fn init(&mut self) {
+        if let Some(addr) = self.options.connect.clone() {
+            self.connect(&addr);
+            return;
+        }
+
+        if let Some(addr) = self.options.watch.clone() {
+            self.watch(&Endpoint::parse(&addr));
+            return;
+        }
+
+        if let Some(addr) = self.options.broadcast.clone() {
+            let bounds = self.config(Duration::from_millis(1000 / FPS)).bounds;
+
+            match Broadcaster::bind(&Endpoint::parse(&addr), bounds) {
+                Ok(broadcaster) => self.broadcaster = Some(broadcaster),
+                Err(err) => {
+                    eprintln!("Cannot broadcast on {}: {}", addr, err);
+                    return;
+                }
+            }
+        }
+
+        if let Some(path) = &self.options.export_stats {
+            if let Err(err) = self.stats.export(path) {
+                eprintln!("Cannot export stats to {}: {}", path.display(), err);
+            }
+
+            return;
+        }
+
+        if let Some(path) = self.options.replay.clone() {
+            match Replay::load(&path) {
+                Ok(replay) => self.replay(&replay),
+                Err(err) => eprintln!("Cannot load replay {}: {}", path.display(), err),
+            }
+
+            return;
+        }
+
         self.screen.enter();
 
-        match self.screen.welcome() {
-            WelcomeAction::Quit => {
-                return;
+        Scenes::new(Box::new(WelcomeScene::new(self))).run(self);
+
+        if !self.errors.is_empty() {
+            self.screen.leave();
+            for error in &self.errors {
+                eprintln!("{}", error);
             }
-            WelcomeAction::Continue => {}
         }
+    }
 
-        match self.screen.menu() {
-            MenuAction::Quit => {
-                return;
+    /// Saves what a finished game changed, but its high score.
+    ///
+    /// Returns whether the game is a new personal best.
+    pub(super) fn save(&mut self, replay: &Replay) -> bool {
+        let errors = &mut self.errors;
+
+        if let Some(path) = &self.options.record {
+            if let Err(err) = replay.save(path) {
+                errors.push(format!("Cannot save replay {}: {}", path.display(), err));
             }
-            MenuAction::Level(_) => {
-                self.play();
+        }
+
+        let dir = match &self.data_dir {
+            Some(dir) => dir,
+            None => return false,
+        };
+
+        let best = Ghost::save_best(dir, replay).unwrap_or_else(|err| {
+            errors.push(format!("Cannot save personal best: {}", err));
+            false
+        });
+
+        if let Err(err) = self.achievements.save(dir) {
+            errors.push(format!("Cannot save achievements: {}", err));
+        }
+
+        if let Err(err) = self.stats.save(dir) {
+            errors.push(format!("Cannot save stats: {}", err));
+        }
+
+        best
+    }
+
+    /// Saves the new `high_score`.
+    pub(super) fn add_high_score(&mut self, high_score: HighScore) {
+        self.high_scores.insert(high_score);
+
+        if let Some(dir) = &self.data_dir {
+            if let Err(err) = self.high_scores.save(dir) {
+                self.errors
+                    .push(format!("Cannot save high scores: {}", err));
             }
         }
     }
 
-    pub fn play(&self) {
+    /// Plays `replay` back.
+    ///
+    /// `Space` pauses, `+`/`-` speed up/slow down, `.` steps one update
+    /// forward and `ESC` quits.
+    pub fn replay(&mut self, replay: &Replay) {
+        self.screen.enter();
+
+        let mpf = replay.config.delta;
+        let mut world = replay.world();
+        let mut hud = Layout::with_arena(replay.config.bounds, self.screen.width()).hud();
+        let mut speed = REPLAY_SPEEDS
+            .iter()
+            .position(|speed| *speed == 1.0)
+            .unwrap_or(0);
+        let mut paused = false;
+        let mut step = false;
+        let mut ticker = Ticker::new(mpf);
+
+        'replay_loop: loop {
+            let ended = world.is_over() || world.tick() >= replay.ticks;
+            if (!paused || step) && !ended {
+                replay.steer(&mut world);
+                world.update();
+            }
+            step = false;
+
+            hud.clear();
+            hud.push(
+                if ended {
+                    "■"
+                } else if paused {
+                    "⏸"
+                } else {
+                    "▶"
+                },
+                format!("x{}", REPLAY_SPEEDS[speed]),
+            );
+            hud.push("Tick", format!("{}/{}", world.tick(), replay.ticks));
+            hud.push("Mode", replay.mode.name());
+            hud.push("Time", clock(world.elapsed()));
+            hud.push("Score", world.score());
+            hud.push("Speed", format!("{:.1}", world.player().speed()));
+            let out = self.screen.out();
+            world.render(out, &self.options.theme).unwrap();
+            write!(out, "{}", hud).unwrap();
+            out.flush().unwrap();
+
+            while let Some(code) = read_key_until(ticker.deadline()) {
+                match code {
+                    KeyCode::Esc => break 'replay_loop,
+                    KeyCode::Char(' ') => paused = !paused,
+                    KeyCode::Char('+') | KeyCode::Up =>
+                        speed = (speed + 1).min(REPLAY_SPEEDS.len() - 1),
+                    KeyCode::Char('-') | KeyCode::Down => speed = speed.saturating_sub(1),
+                    KeyCode::Char('.') | KeyCode::Right => {
+                        paused = true;
+                        step = true;
+                    }
+                    _ => {}
+                }
+            }
+            ticker.tick(mpf.div_f64(REPLAY_SPEEDS[speed]));
+        }
+    }
+
+    /// Joins the multiplayer game hosted on `addr`.
+    pub fn connect(&mut self, addr: &str) {
+        let mut client = match Client::connect(addr) {
+            Ok(client) => client,
+            Err(err) => {
+                eprintln!("Cannot connect to {}: {}", addr, err);
+                return;
+            }
+        };
+
+        self.screen.enter();
+
         let mpf = Duration::from_millis(1000 / FPS);
-        let mut world = World::new(self.screen.size(), mpf);
-
-        'game_loop: loop {
-            let start = Instant::now();
-
-            let events = poll_all();
-            for event in events {
-                match event {
-                    Event::Resize(..) => {}
-                    Event::Mouse(_) => {}
-                    Event::Key(KeyEvent { code, .. }) =>
-                        if let KeyCode::Esc = code {
-                            break 'game_loop;
-                        } else {
-                            world.handle(code);
-                        },
+        let mut snapshot = Snapshot::default();
+        let bounds = client.bounds();
+        let mut hud = Layout::with_arena(bounds, self.screen.width()).hud();
+        let controls = self.controls(0, 1);
+        // Snapshots have no directions: follow the heading for relative
+        // controls, from the one of new snakes
+        let mut heading = Direction::Right;
+        let mut snake = client.snake();
+        let mut ticker = Ticker::new(mpf / 4);
+
+        'client_loop: loop {
+            match client.poll() {
+                Ok(Some(latest)) => snapshot = latest,
+                Ok(None) => {}
+                Err(_) => break 'client_loop,
+            }
+            if client.snake() != snake {
+                snake = client.snake();
+                heading = Direction::Right;
+            }
+
+            hud.clear();
+            if let Some(snake) = snapshot.snakes.get(client.snake()) {
+                hud.push("Score", snake.score);
+                if !snake.alive {
+                    hud.push("Dead", "respawning...");
                 }
             }
+            hud.push(
+                "Players",
+                snapshot.snakes.iter().filter(|snake| snake.alive).count(),
+            );
+            let view = snapshot.view(bounds);
+            let out = self.screen.out();
+            write!(
+                out,
+                "{}{}{}",
+                Clear(ClearType::All),
+                view.themed(&self.options.theme),
+                hud
+            )
+            .unwrap();
+            out.flush().unwrap();
 
-            world.update();
-            world.render();
+            while let Some(code) = read_key_until(ticker.deadline()) {
+                if let KeyCode::Esc = code {
+                    break 'client_loop;
+                } else if let Some(direction) = controls.direction(code, heading) {
+                    if direction != -heading {
+                        heading = direction;
+                    }
+                    if client.steer(snapshot.tick, direction).is_err() {
+                        break 'client_loop;
+                    }
+                }
+            }
+            // Snapshots come at the server's pace, check often for them
+            ticker.tick(mpf / 4);
+        }
+    }
+
+    /// Watches the game streamed on `endpoint`, until it is over or `ESC` is
+    /// pressed.
+    pub fn watch(&mut self, endpoint: &Endpoint) {
+        let mut watcher = match Watcher::connect(endpoint) {
+            Ok(watcher) => watcher,
+            Err(err) => {
+                eprintln!("Cannot watch {}: {}", endpoint, err);
+                return;
+            }
+        };
+
+        self.screen.enter();
 
-            let elapsed = start.elapsed();
-            debug_assert!(elapsed < mpf);
+        let mpf = Duration::from_millis(1000 / FPS);
+        let mut snapshot = Snapshot::default();
+        let bounds = watcher.bounds();
+        let mut hud = Layout::with_arena(bounds, self.screen.width()).hud();
+        let mut ticker = Ticker::new(mpf / 4);
+
+        'watch_loop: loop {
+            match watcher.poll() {
+                Ok(Some(latest)) => snapshot = latest,
+                Ok(None) => {}
+                Err(_) => break 'watch_loop,
+            }
+
+            hud.clear();
+            hud.push("Watching", endpoint);
+            if let Some(snake) = snapshot.snakes.get(watcher.snake()) {
+                hud.push("Score", snake.score);
+            }
+            let view = snapshot.view(bounds);
+            let out = self.screen.out();
+            write!(
+                out,
+                "{}{}{}",
+                Clear(ClearType::All),
+                view.themed(&self.options.theme),
+                hud
+            )
+            .unwrap();
+            out.flush().unwrap();
 
-            sleep(mpf - elapsed);
+            while let Some(code) = read_key_until(ticker.deadline()) {
+                if code == KeyCode::Esc {
+                    break 'watch_loop;
+                }
+            }
+            ticker.tick(mpf / 4);
         }
     }
+
+    /// Returns the `Screen` that `Scene`s render to.
+    pub fn screen(&mut self) -> &mut Screen {
+        &mut self.screen
+    }
+
+    /// Returns the `Controls` of the `player`, among `players` players.
+    pub(super) fn controls(&self, player: usize, players: usize) -> Controls {
+        Controls::for_player(
+            player,
+            players,
+            self.options.relative[player],
+            self.options.diagonals,
+        )
+    }
+
+    /// Returns the `Layout` of the screen while playing.
+    pub(super) fn layout(&self) -> Layout {
+        Layout::new(self.screen.width(), self.screen.height())
+    }
+
+    /// Returns the `Config` of a `World` filling the arena of the `Layout`.
+    pub(super) fn config(&self, delta: Duration) -> Config {
+        Config::new(self.layout().arena, delta)
+    }
 }
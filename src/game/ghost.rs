@@ -0,0 +1,140 @@
+use super::{Config, GameMode, Replay, World};
+use crate::{
+    theme::{Draw, Theme},
+    width,
+};
+use crossterm::style::{Attribute, SetAttribute};
+use std::{
+    fmt::{Error, Formatter},
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// A `Ghost` replays the personal best of a level alongside the live game.
+///
+/// It runs in its own `World`, so that it never collides with the live one.
+#[derive(Debug)]
+pub struct Ghost {
+    replay: Replay,
+    world:  World,
+}
+
+impl Ghost {
+    /// Creates a new `Ghost` replaying `replay`.
+    pub fn new(replay: Replay) -> Self {
+        let world = replay.world();
+
+        Self { replay, world }
+    }
+
+    /// Loads the personal best of `level` with `seed` in `mode` saved in
+    /// `dir`, if any was played with the same `config`.
+    pub fn load(dir: &Path, level: u8, seed: u64, mode: GameMode, config: Config) -> Option<Self> {
+        let replay = Replay::load(path(dir, level, seed, mode)).ok()?;
+
+        if replay.config == config && replay.players == 1 {
+            Some(Self::new(replay))
+        } else {
+            None
+        }
+    }
+
+    /// Saves `replay` in `dir` as the personal best of its level, seed and
+    /// mode if it scored more than the current one, in a single player game.
+    ///
+    /// Returns whether it is a new personal best.
+    pub fn save_best(dir: &Path, replay: &Replay) -> io::Result<bool> {
+        if replay.players != 1 {
+            return Ok(false);
+        }
+
+        let path = path(dir, replay.level, replay.seed, replay.mode);
+
+        match Replay::load(&path) {
+            Ok(best) if best.score >= replay.score => return Ok(false),
+            Err(err)
+                if err.kind() != ErrorKind::NotFound && err.kind() != ErrorKind::InvalidData =>
+                return Err(err),
+            _ => {}
+        }
+
+        replay.save(&path)?;
+        Ok(true)
+    }
+
+    /// Returns the score of the ghost at the current tick.
+    pub fn score(&self) -> u32 {
+        self.world.score()
+    }
+
+    /// Returns whether the personal best is over at the current tick.
+    pub fn is_over(&self) -> bool {
+        self.world.is_over() || self.world.tick() >= self.replay.ticks
+    }
+
+    /// Moves the ghost one update forward.
+    pub fn update(&mut self) {
+        if self.is_over() {
+            return;
+        }
+
+        self.replay.steer(&mut self.world);
+        self.world.update();
+    }
+}
+
+impl Draw for Ghost {
+    fn draw(&self, f: &mut Formatter, theme: &Theme) -> Result<(), Error> {
+        if self.is_over() {
+            return Ok(());
+        }
+
+        let glyph = theme.ghost;
+
+        write!(f, "{}", SetAttribute(Attribute::Dim))?;
+        for point in self.world.player().body() {
+            write!(f, "{}{}", width::move_to(*point), glyph)?;
+        }
+        write!(f, "{}", SetAttribute(Attribute::Reset))
+    }
+}
+
+/// Returns the path in `dir` of the personal best, classic ones keeping
+/// their original name.
+fn path(dir: &Path, level: u8, seed: u64, mode: GameMode) -> PathBuf {
+    match mode {
+        GameMode::Classic => dir.join(format!("best-{}-{:x}.snkr", level, seed)),
+        _ => dir.join(format!("best-{}-{:x}-{}.snkr", level, seed, mode.id())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::Direction;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn races_the_replay() {
+        let config = Config::new((30.0, 20.0), Duration::from_millis(200));
+        let mut replay = Replay::new(7, 0, config);
+        replay.record(2, 0, Direction::Up);
+        replay.ticks = 10;
+
+        let mut ghost = Ghost::new(replay.clone());
+        let mut world = replay.world();
+        for _ in 0..10 {
+            assert!(!ghost.is_over());
+
+            replay.steer(&mut world);
+            world.update();
+            ghost.update();
+
+            assert_eq!(ghost.score(), world.score());
+            assert_eq!(ghost.world.player().head(), world.player().head());
+        }
+
+        assert!(ghost.is_over());
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    consts::{MAX_ARENA_HEIGHT, MAX_ARENA_WIDTH},
+    physics::Point,
+    width::{self, width},
+};
+use crossterm::{
+    cursor::MoveTo,
+    style::{Attribute, SetAttribute},
+    terminal::{Clear, ClearType},
+};
+use std::{
+    fmt::{Display, Error, Formatter},
+    time::Duration,
+};
+
+/// The height of the `Hud`, in rows.
+pub const HUD_HEIGHT: u16 = 1;
+
+/// The `Layout` of the screen while playing: the arena from the top left
+/// corner, and the `Hud` on the rows below, so that they never overlap.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Layout {
+    /// The size of the arena, in cells.
+    pub arena: Point,
+    /// The row of the `Hud`.
+    pub hud_y: u16,
+    /// The width of the screen, in columns.
+    pub width: u16,
+}
+
+/// The `Hud` shows information about the game on a line below the arena.
+#[derive(Clone, Debug)]
+pub struct Hud {
+    y:      u16,
+    width:  u16,
+    fields: Vec<(&'static str, String)>,
+}
+
+impl Layout {
+    /// Creates the `Layout` of a screen of `width` × `height` columns and
+    /// rows, the arena filling what the `Hud` leaves up to the largest one
+    /// supported.
+    pub fn new(width: u16, height: u16) -> Self {
+        let arena_width = width::cells(width).min(MAX_ARENA_WIDTH);
+        let arena_height = height.saturating_sub(HUD_HEIGHT).min(MAX_ARENA_HEIGHT);
+
+        Self {
+            arena: Point::new(f64::from(arena_width), f64::from(arena_height)),
+            hud_y: arena_height,
+            width,
+        }
+    }
+
+    /// Creates the `Layout` of an arena of a given size, as in replays and
+    /// online games, on a screen `width` columns wide.
+    pub fn with_arena(arena: Point, width: u16) -> Self {
+        Self {
+            arena,
+            hud_y: arena.y as u16,
+            width,
+        }
+    }
+
+    /// Returns an empty `Hud` at its place.
+    pub fn hud(&self) -> Hud {
+        Hud {
+            y:      self.hud_y,
+            width:  self.width,
+            fields: vec![],
+        }
+    }
+}
+
+impl Hud {
+    /// Removes all the fields.
+    pub fn clear(&mut self) {
+        self.fields.clear();
+    }
+
+    /// Adds a field.
+    pub fn push(&mut self, label: &'static str, value: impl Display) {
+        self.fields.push((label, value.to_string()));
+    }
+
+    /// Returns the fields that fit in the width of the `Hud`, in order.
+    ///
+    /// A field that does not fit is dropped rather than wrapped onto the
+    /// arena.
+    fn visible(&self) -> impl Iterator<Item = &(&'static str, String)> {
+        let mut used = 0;
+
+        self.fields.iter().take_while(move |(label, value)| {
+            used += width(label) + width(value) + 3;
+            used <= self.width
+        })
+    }
+}
+
+impl Display for Hud {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}{}", MoveTo(0, self.y), Clear(ClearType::CurrentLine))?;
+
+        for (label, value) in self.visible() {
+            write!(
+                f,
+                " {} {}{}{} ",
+                label,
+                SetAttribute(Attribute::Bold),
+                value,
+                SetAttribute(Attribute::Reset)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats `duration` as a clock, like `1:05`.
+pub fn clock(duration: Duration) -> String {
+    let secs = duration.as_secs();
+
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn layout() {
+        let layout = Layout::new(81, 30);
+        assert_eq!(layout.arena, Point::new(40.0, 29.0));
+        assert_eq!(layout.hud_y, 29);
+
+        let layout = Layout::with_arena(Point::new(20.0, 10.0), 81);
+        assert_eq!(layout.hud_y, 10);
+    }
+
+    #[test]
+    fn fits() {
+        let mut hud = Layout::new(20, 10).hud();
+        hud.push("Score", 120);
+        hud.push("🐍", "ok");
+        hud.push("Speed", 10);
+
+        // " Score 120 " then " 🐍 ok "
+        assert_eq!(hud.visible().count(), 2);
+    }
+
+    #[test]
+    fn clocks() {
+        assert_eq!(clock(Duration::from_millis(4_900)), "0:04");
+        assert_eq!(clock(Duration::from_secs(65)), "1:05");
+    }
+}
@@ -0,0 +1,211 @@
+use super::Obstacle;
+use crate::{ai::Difficulty, physics::Direction};
+
+/// A `Level`, as listed in the menu.
+#[derive(Copy, Clone, Debug)]
+pub struct Level {
+    /// The name of the level.
+    pub name:      &'static str,
+    /// The AI opponents, one per `Difficulty`.
+    pub opponents: &'static [Difficulty],
+    /// The `Obstacle`s on the terrain.
+    pub obstacles: &'static [Obstacle],
+    /// How fast the snakes go as they eat.
+    pub speed:     SpeedCurve,
+    /// The score that finishes the level in classic mode, if any.
+    pub goal:      Option<u32>,
+}
+
+/// A `SpeedCurve` maps the number of foods a snake has eaten to its speed,
+/// in cells per second.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SpeedCurve {
+    /// The speed of a snake that has not eaten yet.
+    pub base:     f64,
+    /// The speed gained per food eaten.
+    pub per_food: f64,
+    /// The maximum speed.
+    pub max:      f64,
+}
+
+impl SpeedCurve {
+    /// Creates a `SpeedCurve` that stays at `speed`.
+    pub const fn constant(speed: f64) -> Self {
+        Self {
+            base:     speed,
+            per_food: 0.0,
+            max:      speed,
+        }
+    }
+
+    /// Returns the speed after eating `eaten` foods.
+    pub fn at(&self, eaten: u32) -> f64 {
+        (self.base + self.per_food * f64::from(eaten)).min(self.max)
+    }
+}
+
+impl Level {
+    /// Returns the `Level` with the given `id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no such level.
+    pub fn get(id: u8) -> &'static Self {
+        &LEVELS[usize::from(id)]
+    }
+
+    /// Returns the id of the `Level` named `name`, if any.
+    pub fn find(name: &str) -> Option<u8> {
+        LEVELS
+            .iter()
+            .position(|level| level.name == name)
+            .map(|id| id as u8)
+    }
+}
+
+pub const LEVELS: [Level; 5] = [
+    Level {
+        name:      "Snake Pit",
+        opponents: &[Difficulty::Easy],
+        obstacles: &[],
+        speed:     SpeedCurve {
+            base:     8.0,
+            per_food: 0.25,
+            max:      14.0,
+        },
+        goal:      Some(100),
+    },
+    Level {
+        name:      "Snake Den",
+        opponents: &[Difficulty::Normal],
+        obstacles: &[Obstacle::Border],
+        speed:     SpeedCurve {
+            base:     10.0,
+            per_food: 0.25,
+            max:      16.0,
+        },
+        goal:      Some(150),
+    },
+    Level {
+        name:      "Lol",
+        opponents: &[Difficulty::Easy, Difficulty::Easy, Difficulty::Easy],
+        obstacles: &[
+            Obstacle::Rocks {
+                x:      0.5,
+                y:      0.15,
+                width:  1,
+                height: 3,
+            },
+            Obstacle::Rocks {
+                x:      0.5,
+                y:      0.7,
+                width:  1,
+                height: 3,
+            },
+            Obstacle::Portals {
+                from: (0.1, 0.1),
+                to:   (0.9, 0.9),
+                exit: None,
+            },
+        ],
+        speed:     SpeedCurve {
+            base:     10.0,
+            per_food: 0.5,
+            max:      18.0,
+        },
+        goal:      Some(200),
+    },
+    Level {
+        name:      "Snake ...",
+        opponents: &[Difficulty::Hard],
+        obstacles: &[
+            Obstacle::Border,
+            Obstacle::Rocks {
+                x:      0.5,
+                y:      0.2,
+                width:  1,
+                height: 4,
+            },
+            Obstacle::Rocks {
+                x:      0.5,
+                y:      0.65,
+                width:  1,
+                height: 4,
+            },
+        ],
+        speed:     SpeedCurve {
+            base:     12.0,
+            per_food: 0.5,
+            max:      20.0,
+        },
+        goal:      Some(250),
+    },
+    Level {
+        name:      "Snake ... HARDCORE",
+        opponents: &[Difficulty::Hard, Difficulty::Hard, Difficulty::Normal],
+        obstacles: &[
+            Obstacle::Border,
+            Obstacle::Rocks {
+                x:      0.3,
+                y:      0.15,
+                width:  2,
+                height: 2,
+            },
+            Obstacle::Rocks {
+                x:      0.6,
+                y:      0.15,
+                width:  2,
+                height: 2,
+            },
+            Obstacle::Rocks {
+                x:      0.3,
+                y:      0.75,
+                width:  2,
+                height: 2,
+            },
+            Obstacle::Rocks {
+                x:      0.6,
+                y:      0.75,
+                width:  2,
+                height: 2,
+            },
+            Obstacle::Portals {
+                from: (0.1, 0.5),
+                to:   (0.9, 0.5),
+                exit: Some(Direction::Left),
+            },
+        ],
+        speed:     SpeedCurve {
+            base:     14.0,
+            per_food: 0.5,
+            max:      25.0,
+        },
+        goal:      Some(300),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn speed_curve() {
+        let curve = SpeedCurve {
+            base:     10.0,
+            per_food: 0.5,
+            max:      12.0,
+        };
+
+        assert_eq!(curve.at(0), 10.0);
+        assert_eq!(curve.at(3), 11.5);
+        assert_eq!(curve.at(100), 12.0);
+        assert_eq!(SpeedCurve::constant(8.0).at(10), 8.0);
+    }
+
+    #[test]
+    fn find() {
+        assert_eq!(Level::find("Snake Den"), Some(1));
+        assert_eq!(Level::find("Snake Nest"), None);
+    }
+}
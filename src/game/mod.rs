@@ -1,11 +1,44 @@
 //! Where the fun happens
 
+mod achievements;
+mod config;
 mod food;
+#[allow(clippy::module_inception)]
 mod game;
+mod ghost;
+mod hud;
+mod level;
+mod mode;
+mod outcome;
+mod play;
+mod replay;
+mod scene;
+mod scenes;
+mod scores;
 mod snake;
+mod snapshot;
+mod stats;
+mod terrain;
+mod toast;
 mod world;
 
+pub use achievements::*;
+pub use config::*;
 pub use food::*;
 pub use game::*;
+pub use ghost::*;
+pub use hud::*;
+pub use level::*;
+pub use mode::*;
+pub use outcome::*;
+pub use play::*;
+pub use replay::*;
+pub use scene::*;
+pub use scenes::*;
+pub use scores::*;
 pub use snake::*;
+pub use snapshot::*;
+pub use stats::*;
+pub use terrain::*;
+pub use toast::*;
 pub use world::*;
@@ -0,0 +1,79 @@
+use super::{clock, Hud, World};
+use std::time::Duration;
+
+/// How long a time attack lasts.
+pub const TIME_ATTACK: Duration = Duration::from_secs(60);
+
+/// How often a rock appears in survival.
+pub const SURVIVAL_ROCK_EVERY: Duration = Duration::from_secs(3);
+
+/// The `GameMode`, which sets the rules of the game.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum GameMode {
+    /// Die on collision.
+    #[default]
+    Classic,
+    /// Eat as much as possible before the time is up.
+    TimeAttack,
+    /// Survive while rocks keep appearing.
+    Survival,
+    /// No death by snakes, which pass through themselves and each other.
+    /// Walls and rocks still kill.
+    Zen,
+}
+
+impl GameMode {
+    /// All the `GameMode`s, as listed in the menu.
+    pub const ALL: [GameMode; 4] = [Self::Classic, Self::TimeAttack, Self::Survival, Self::Zen];
+
+    /// Returns the `GameMode` with the given `id`, if any.
+    pub fn get(id: u8) -> Option<Self> {
+        Self::ALL.get(usize::from(id)).copied()
+    }
+
+    /// Returns the id of this `GameMode`, its index in `GameMode::ALL`.
+    pub fn id(self) -> u8 {
+        Self::ALL
+            .iter()
+            .position(|mode| *mode == self)
+            .expect("All modes are listed") as u8
+    }
+
+    /// Returns the name of this `GameMode`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Classic => "Classic",
+            Self::TimeAttack => "Time attack",
+            Self::Survival => "Survival",
+            Self::Zen => "Zen",
+        }
+    }
+
+    /// Returns whether snakes die on hitting a snake, themselves included.
+    pub fn snakes_collide(self) -> bool {
+        self != Self::Zen
+    }
+
+    /// Pushes the fields specific to this `GameMode` about `world` to `hud`.
+    pub fn hud(self, world: &World, hud: &mut Hud) {
+        match self {
+            Self::Classic | Self::Zen => {}
+            Self::TimeAttack => hud.push("Time left", clock(world.time_left().unwrap_or_default())),
+            Self::Survival => hud.push("Rocks", world.terrain().rocks()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn ids() {
+        for mode in GameMode::ALL.iter() {
+            assert_eq!(GameMode::get(mode.id()), Some(*mode));
+        }
+        assert_eq!(GameMode::get(4), None);
+    }
+}
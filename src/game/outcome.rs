@@ -0,0 +1,74 @@
+use super::{Death, World};
+
+/// The `Outcome` of a finished game, as shown on the game-over screen.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Outcome {
+    /// How the player died, if they did.
+    pub death:    Option<Death>,
+    /// Whether the time was up.
+    pub time_up:  bool,
+    /// Whether the player finished the level.
+    pub finished: bool,
+    /// The final score of the player.
+    pub score:    u32,
+    /// The final length of the player.
+    pub length:   u32,
+    /// Whether the score is a new personal best.
+    pub best:     bool,
+}
+
+impl Outcome {
+    /// Creates the `Outcome` of the player of `world`, not a personal best.
+    pub fn new(world: &World) -> Self {
+        let player = world.player();
+
+        Self {
+            death:    player.death(),
+            time_up:  world.time_left().is_some_and(|left| left.is_zero()),
+            finished: world.is_finished(),
+            score:    world.score(),
+            length:   player.body().count() as u32,
+            best:     false,
+        }
+    }
+
+    /// Returns why the game ended.
+    pub fn cause(&self) -> &'static str {
+        match self.death {
+            Some(death) => death.name(),
+            None if self.time_up => "Time's up",
+            None if self.finished => "Level complete",
+            None => "Gave up",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Config, Level};
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    #[test]
+    fn causes() {
+        let config = Config::new((20.0, 10.0), Duration::from_millis(100));
+        let mut world = World::new(config, 42, Level::get(1));
+        assert_eq!(Outcome::new(&world).cause(), "Gave up");
+
+        while !world.is_over() {
+            world.update();
+        }
+        let outcome = Outcome::new(&world);
+        assert_eq!(outcome.cause(), "Hit a wall");
+        assert_eq!(outcome.score, world.score());
+
+        let outcome = Outcome {
+            death: None,
+            time_up: true,
+            finished: false,
+            ..outcome
+        };
+        assert_eq!(outcome.cause(), "Time's up");
+    }
+}
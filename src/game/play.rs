@@ -0,0 +1,376 @@
+use super::{
+    clock,
+    Achievements,
+    Config,
+    Game,
+    GameMode,
+    GameOverScene,
+    Ghost,
+    Hud,
+    Layout,
+    Level,
+    NameEntryScene,
+    Outcome,
+    PauseScene,
+    Replay,
+    Request,
+    Scene,
+    Toast,
+    Tracker,
+    Transition,
+    World,
+};
+use crate::{
+    cli::Options,
+    consts::{FPS, TOAST_FRAMES},
+    events::{Controls, KeyCode},
+    net::Broadcaster,
+    physics::Direction,
+    theme::{Draw, Theme},
+};
+use crossterm::terminal::{Clear, ClearType};
+use std::{collections::VecDeque, io::Write, time::Duration};
+
+/// The `PlayScene` plays a level, until the game is over.
+///
+/// The personal best on this level, if any, races along as a `Ghost` in
+/// single player games, which also unlock achievements.
+#[derive(Debug)]
+pub struct PlayScene {
+    id:       u8,
+    mode:     GameMode,
+    replay:   Replay,
+    world:    World,
+    ghost:    Option<Ghost>,
+    tracker:  Option<Tracker>,
+    toast:    Toast,
+    hud:      Hud,
+    controls: Vec<Controls>,
+    theme:    Theme,
+    /// The relative turns of each player waiting for the previous one to be
+    /// taken, so that quick turns are not lost.
+    turns:    Vec<VecDeque<Direction>>,
+}
+
+impl PlayScene {
+    /// Creates a new `PlayScene` of the level with the given `id` in `mode`
+    /// from `seed`.
+    pub fn new(game: &Game, id: u8, mode: GameMode, seed: u64) -> Self {
+        let mut scene = Self::with_options(&game.options, game.layout(), id, mode, seed);
+        let config = scene.replay.config;
+        scene.ghost = game
+            .data_dir
+            .as_deref()
+            .and_then(|dir| Ghost::load(dir, id, seed, mode, config))
+            .filter(|_| scene.world.players() == 1);
+
+        scene
+    }
+
+    /// Creates a new `PlayScene` like `new`, on a screen laid out as `layout`
+    /// and without the personal best.
+    pub fn with_options(
+        options: &Options,
+        layout: Layout,
+        id: u8,
+        mode: GameMode,
+        seed: u64,
+    ) -> Self {
+        let config = Config::new(layout.arena, Duration::from_millis(1000 / FPS));
+        let players = if options.two_players { 2 } else { 1 };
+        let replay = Replay::new(seed, id, config)
+            .with_players(players)
+            .with_mode(mode);
+
+        Self {
+            id,
+            mode,
+            world: replay.world(),
+            ghost: None,
+            tracker: Some(Tracker::new(id, mode)).filter(|_| players == 1),
+            toast: Toast::default(),
+            hud: layout.hud(),
+            controls: (0..usize::from(players))
+                .map(|player| {
+                    Controls::for_player(
+                        player,
+                        usize::from(players),
+                        options.relative[player],
+                        options.diagonals,
+                    )
+                })
+                .collect(),
+            theme: options.theme,
+            turns: vec![VecDeque::new(); usize::from(players)],
+            replay,
+        }
+    }
+
+    /// Steers the snakes of the players whose controls include `code`.
+    ///
+    /// Relative turns are queued after the pending ones.
+    fn steer(&mut self, code: KeyCode) {
+        for player in 0..self.controls.len() {
+            let controls = self.controls[player];
+            let snake = &self.world.snakes()[player];
+            let turns = &mut self.turns[player];
+
+            if controls.is_relative() {
+                let heading = turns
+                    .back()
+                    .copied()
+                    .unwrap_or_else(|| snake.draft_direction());
+                turns.extend(controls.direction(code, heading));
+            } else if let Some(direction) = controls.direction(code, snake.direction()) {
+                self.steer_snake(player, direction);
+            }
+        }
+
+        self.turn();
+    }
+
+    /// Steers the snakes to their next queued turn, once they took the
+    /// previous one.
+    fn turn(&mut self) {
+        for player in 0..self.turns.len() {
+            let snake = &self.world.snakes()[player];
+
+            if snake.direction() == snake.draft_direction() {
+                if let Some(direction) = self.turns[player].pop_front() {
+                    self.steer_snake(player, direction);
+                }
+            }
+        }
+    }
+
+    /// Steers the snake of `player` to `direction`, recording it.
+    fn steer_snake(&mut self, player: usize, direction: Direction) {
+        let heading = self.world.snakes()[player].direction();
+
+        self.world.steer_snake(player, direction);
+        self.replay
+            .record(self.world.tick(), player as u8, direction);
+        if let Some(tracker) = &mut self.tracker {
+            tracker.steer(heading, direction);
+        }
+    }
+
+    /// Moves the game one frame forward, recording the progress of the
+    /// player in `achievements`.
+    fn step(&mut self, achievements: &mut Achievements) {
+        self.turn();
+        self.world.update();
+
+        self.toast.tick();
+        if let Some(tracker) = &mut self.tracker {
+            for achievement in tracker.update(&self.world, achievements) {
+                self.toast.show(
+                    format!(
+                        "{}Achievement unlocked: {}",
+                        self.theme.unlocked,
+                        achievement.name()
+                    ),
+                    TOAST_FRAMES,
+                );
+            }
+        }
+
+        if let Some(ghost) = &mut self.ghost {
+            ghost.update();
+        }
+    }
+
+    /// Draws the game on `out`, with the number of `viewers` if broadcast.
+    fn draw(&mut self, out: &mut (impl Write + ?Sized), viewers: Option<usize>) {
+        let world = &self.world;
+        let hud = &mut self.hud;
+
+        hud.clear();
+        hud.push("Level", Level::get(self.id).name);
+        if world.players() == 1 {
+            hud.push("Score", world.score());
+            if let Some(goal) = world.goal() {
+                hud.push("Goal", goal);
+            }
+        } else {
+            hud.push("P1", world.score_of(0));
+            hud.push("P2", world.score_of(1));
+        }
+        hud.push("Length", world.player().body().count());
+        hud.push("Time", clock(world.elapsed()));
+        self.mode.hud(world, hud);
+        hud.push("Speed", format!("{:.1}", world.player().speed()));
+        let effects = world.effects();
+        if !effects.is_empty() {
+            hud.push("Effects", effects.join(", "));
+        }
+        if let Some(ghost) = &self.ghost {
+            hud.push(
+                "Ghost",
+                format!("{:+}", i64::from(world.score()) - i64::from(ghost.score())),
+            );
+        }
+        if let Some(viewers) = viewers {
+            hud.push("Viewers", viewers);
+        }
+
+        write!(out, "{}", Clear(ClearType::All)).unwrap();
+        if let Some(ghost) = &self.ghost {
+            write!(out, "{}", ghost.themed(&self.theme)).unwrap();
+        }
+        write!(out, "{}{}{}", world.themed(&self.theme), hud, self.toast).unwrap();
+        out.flush().unwrap();
+    }
+
+    /// Ends the game, saving what it changed, then shows the game-over
+    /// screen, or passes `request` on.
+    ///
+    /// A new high score first asks for a name.
+    fn finish(&mut self, game: &mut Game, request: Option<Request>) -> Transition {
+        game.stats.record(self.id, &self.world);
+
+        self.replay.ticks = self.world.tick();
+        self.replay.score = self.world.score();
+
+        let mut outcome = Outcome::new(&self.world);
+        outcome.best = game.save(&self.replay);
+
+        let then = match request {
+            Some(request) => Transition::Request(request),
+            None => Transition::Replace(Box::new(GameOverScene::new(
+                game,
+                outcome,
+                self.id,
+                self.mode,
+                self.replay.seed,
+            ))),
+        };
+        let score = self.replay.score;
+        if self.replay.players == 1 && game.high_scores.qualifies(score) {
+            let scene = NameEntryScene::new(game, score, self.id, self.mode, then);
+
+            Transition::Replace(Box::new(scene))
+        } else {
+            then
+        }
+    }
+}
+
+impl Scene for PlayScene {
+    fn period(&self) -> Option<Duration> {
+        Some(self.replay.config.delta)
+    }
+
+    fn handle(&mut self, game: &mut Game, code: KeyCode) -> Option<Transition> {
+        match code {
+            KeyCode::Esc => Some(self.finish(game, None)),
+            KeyCode::Char(' ') | KeyCode::Char('p') =>
+                Some(Transition::Push(Box::new(PauseScene::new(game)))),
+            _ => {
+                self.steer(code);
+                None
+            }
+        }
+    }
+
+    fn update(&mut self, game: &mut Game) -> Option<Transition> {
+        self.step(&mut game.achievements);
+
+        if let Some(broadcaster) = &game.broadcaster {
+            broadcaster.publish(&self.world.snapshot());
+        }
+
+        if self.world.is_over() {
+            Some(self.finish(game, None))
+        } else {
+            None
+        }
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        let viewers = game.broadcaster.as_ref().map(Broadcaster::viewers);
+
+        self.draw(game.screen.out(), viewers);
+    }
+
+    /// Ends the game before passing `request` on.
+    fn request(&mut self, game: &mut Game, request: Request) -> Option<Transition> {
+        Some(self.finish(game, Some(request)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backend::MemoryBackend,
+        game::{MenuScene, Scenes},
+        testing::{self, assert_snapshot, run},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn play(ticks: u32, script: &[(u32, KeyCode)]) -> MemoryBackend {
+        run(40, 12, ticks, script, |game| {
+            let layout = game.layout();
+
+            Box::new(PlayScene::with_options(
+                &game.options,
+                layout,
+                0,
+                GameMode::Classic,
+                42,
+            ))
+        })
+    }
+
+    #[test]
+    fn start() {
+        assert_snapshot("play_start", &play(1, &[]));
+    }
+
+    #[test]
+    fn quit_from_pause() {
+        let (mut game, _) = testing::game(40, 12);
+        let play = PlayScene::with_options(&game.options, game.layout(), 0, GameMode::Classic, 42);
+        let mut scenes = Scenes::new(Box::new(MenuScene::new(&game, None)));
+        scenes.apply(&mut game, Transition::Push(Box::new(play)));
+
+        scenes.frame(&mut game, vec![KeyCode::Char('p')]);
+        assert_eq!(scenes.len(), 3);
+        // Up wraps around to "Quit"
+        scenes.frame(&mut game, vec![KeyCode::Up, KeyCode::Enter]);
+        assert!(scenes.is_empty());
+        assert_eq!(game.stats.average_score(0), Some(0.0));
+    }
+
+    #[test]
+    fn relative_turns() {
+        let options = Options {
+            relative: [true, false],
+            ..Options::default()
+        };
+        let layout = Layout::new(40, 12);
+        let mut scene = PlayScene::with_options(&options, layout, 0, GameMode::Classic, 42);
+        let mut achievements = Achievements::default();
+        let direction = |scene: &PlayScene| scene.world.player().direction();
+
+        // Right twice within a tick is a U-turn
+        scene.steer(KeyCode::Right);
+        scene.steer(KeyCode::Right);
+        assert_eq!(direction(&scene), Direction::Right);
+        scene.step(&mut achievements);
+        assert_eq!(direction(&scene), Direction::Down);
+        scene.step(&mut achievements);
+        assert_eq!(direction(&scene), Direction::Left);
+        scene.step(&mut achievements);
+        assert_eq!(direction(&scene), Direction::Left);
+    }
+
+    #[test]
+    fn steer() {
+        assert_snapshot(
+            "play_steer",
+            &play(12, &[(4, KeyCode::Down), (8, KeyCode::Left)]),
+        );
+    }
+}
@@ -0,0 +1,483 @@
+use super::{Config, GameMode, Level, World, LEVELS};
+use crate::{
+    consts::{MAX_ARENA_HEIGHT, MAX_ARENA_WIDTH},
+    physics::{Direction, Point},
+};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Error, ErrorKind, Read, Result, Write},
+    path::Path,
+    time::Duration,
+};
+
+/// The first bytes of a replay file.
+const MAGIC: &[u8; 4] = b"SNKR";
+
+/// The version of the replay file format.
+const VERSION: u8 = 1;
+
+/// An `Input` of a player, applied before the update of `tick`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Input {
+    /// The tick of the `World` when the input happened.
+    pub tick:      u32,
+    /// The player who steered, an index in `World::snakes`.
+    pub player:    u8,
+    /// The `Direction` the player steered to.
+    pub direction: Direction,
+}
+
+/// A `Replay` holds everything needed to play a game again, update by
+/// update.
+///
+/// # File format
+///
+/// All integers are little endian, `varint`s are LEB128:
+///
+/// | Field            | Type              |
+/// |------------------|-------------------|
+/// | Magic (`SNKR`)   | `[u8; 4]`         |
+/// | Version          | `u8`              |
+/// | Seed             | `u64`             |
+/// | Level id         | `u8`              |
+/// | Players          | `u8`              |
+/// | Mode id          | `u8`              |
+/// | Width, height    | `u16`, `u16`      |
+/// | Delta (µs)       | `u32`             |
+/// | Ticks            | `u32`             |
+/// | Score            | `u32`             |
+/// | Inputs count     | `u32`             |
+/// | Inputs           | `(varint, u8)`... |
+///
+/// Each input is its tick minus the previous input's, then its player in the
+/// high nibble and its direction as an index in `Direction::EIGHT` in the low
+/// nibble.
+///
+/// Arenas are at most `MAX_ARENA_WIDTH` × `MAX_ARENA_HEIGHT` cells.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Replay {
+    /// The seed of the `World`.
+    pub seed:    u64,
+    /// The id of the `Level`.
+    pub level:   u8,
+    /// The number of human players.
+    pub players: u8,
+    /// The `GameMode`.
+    pub mode:    GameMode,
+    /// The `Config` of the `World`.
+    pub config:  Config,
+    /// The number of updates of the game.
+    pub ticks:   u32,
+    /// The final score of the game.
+    pub score:   u32,
+    /// The `Input`s of the players, by tick.
+    pub inputs:  Vec<Input>,
+}
+
+impl Replay {
+    /// Creates a new empty `Replay` of a single player game.
+    pub fn new(seed: u64, level: u8, config: Config) -> Self {
+        Self {
+            seed,
+            level,
+            players: 1,
+            mode: GameMode::Classic,
+            config,
+            ticks: 0,
+            score: 0,
+            inputs: vec![],
+        }
+    }
+
+    /// Sets the number of human players.
+    pub fn with_players(mut self, players: u8) -> Self {
+        self.players = players;
+        self
+    }
+
+    /// Sets the `GameMode`.
+    pub fn with_mode(mut self, mode: GameMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Creates the `World` at the start of the game.
+    pub fn world(&self) -> World {
+        World::with_players(
+            self.config,
+            self.seed,
+            Level::get(self.level),
+            usize::from(self.players),
+        )
+        .with_mode(self.mode)
+    }
+
+    /// Records an `Input` of `player`.
+    pub fn record(&mut self, tick: u32, player: u8, direction: Direction) {
+        debug_assert!(self.inputs.last().iter().all(|last| last.tick <= tick));
+
+        self.inputs.push(Input {
+            tick,
+            player,
+            direction,
+        });
+    }
+
+    /// Returns the `Input`s at `tick`.
+    pub fn inputs_at(&self, tick: u32) -> impl Iterator<Item = Input> + '_ {
+        let start = self.inputs.partition_point(|input| input.tick < tick);
+
+        self.inputs[start..]
+            .iter()
+            .take_while(move |input| input.tick == tick)
+            .cloned()
+    }
+
+    /// Steers the players of `world` with the `Input`s at its tick.
+    pub fn steer(&self, world: &mut World) {
+        for input in self.inputs_at(world.tick()) {
+            world.steer_snake(usize::from(input.player), input.direction);
+        }
+    }
+
+    /// Loads a `Replay` from the file at `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::read(BufReader::new(File::open(path)?))
+    }
+
+    /// Saves this `Replay` to the file at `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Reads a `Replay` from `reader`.
+    pub fn read(mut reader: impl Read) -> Result<Self> {
+        let mut magic = [0; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != MAGIC {
+            return Err(invalid("Not a replay file"));
+        }
+
+        let version = read_u8(&mut reader)?;
+        if version != VERSION {
+            return Err(invalid(format!("Unsupported replay version {}", version)));
+        }
+
+        let seed = u64::from_le_bytes(read_array(&mut reader)?);
+        let level = read_u8(&mut reader)?;
+        if usize::from(level) >= LEVELS.len() {
+            return Err(invalid(format!("Unknown level {}", level)));
+        }
+
+        let players = read_u8(&mut reader)?;
+        if players == 0 {
+            return Err(invalid("No players"));
+        }
+
+        let id = read_u8(&mut reader)?;
+        let mode = GameMode::get(id).ok_or_else(|| invalid(format!("Unknown mode {}", id)))?;
+
+        let width = u16::from_le_bytes(read_array(&mut reader)?);
+        let height = u16::from_le_bytes(read_array(&mut reader)?);
+        let delta = u32::from_le_bytes(read_array(&mut reader)?);
+        if width == 0 || height == 0 || delta == 0 {
+            return Err(invalid("Empty world"));
+        }
+        if width > MAX_ARENA_WIDTH || height > MAX_ARENA_HEIGHT {
+            return Err(invalid(format!("World too large, {}×{}", width, height)));
+        }
+        let config = Config::new(
+            Point::new(f64::from(width), f64::from(height)),
+            Duration::from_micros(u64::from(delta)),
+        );
+
+        let ticks = u32::from_le_bytes(read_array(&mut reader)?);
+        let score = u32::from_le_bytes(read_array(&mut reader)?);
+        let count = u32::from_le_bytes(read_array(&mut reader)?);
+
+        let mut inputs = Vec::new();
+        let mut tick = 0_u32;
+        for _ in 0..count {
+            tick = tick
+                .checked_add(read_varint(&mut reader)?)
+                .ok_or_else(|| invalid("Tick overflow"))?;
+            let byte = read_u8(&mut reader)?;
+            let player = byte >> 4;
+            if player >= players {
+                return Err(invalid(format!("Unknown player {}", player)));
+            }
+            let direction = *Direction::EIGHT
+                .get(usize::from(byte & 0x0f))
+                .ok_or_else(|| invalid("Unknown direction"))?;
+
+            inputs.push(Input {
+                tick,
+                player,
+                direction,
+            });
+        }
+
+        Ok(Self {
+            seed,
+            level,
+            players,
+            mode,
+            config,
+            ticks,
+            score,
+            inputs,
+        })
+    }
+
+    /// Writes this `Replay` to `writer`.
+    ///
+    /// Fails with `ErrorKind::InvalidInput` if an `Input` is from an unknown
+    /// player, or one that does not fit in its 4 bits.
+    pub fn write(&self, mut writer: impl Write) -> Result<()> {
+        if let Some(input) = self
+            .inputs
+            .iter()
+            .find(|input| input.player >= self.players || input.player > 0x0f)
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("Unknown player {}", input.player),
+            ));
+        }
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        writer.write_all(&self.seed.to_le_bytes())?;
+        writer.write_all(&[self.level])?;
+        writer.write_all(&[self.players])?;
+        writer.write_all(&[self.mode.id()])?;
+        writer.write_all(&(self.config.bounds.x as u16).to_le_bytes())?;
+        writer.write_all(&(self.config.bounds.y as u16).to_le_bytes())?;
+        writer.write_all(&(self.config.delta.as_micros() as u32).to_le_bytes())?;
+        writer.write_all(&self.ticks.to_le_bytes())?;
+        writer.write_all(&self.score.to_le_bytes())?;
+        writer.write_all(&(self.inputs.len() as u32).to_le_bytes())?;
+
+        let mut tick = 0;
+        for input in &self.inputs {
+            write_varint(&mut writer, input.tick - tick)?;
+            writer.write_all(&[input.player << 4 | direction_to_u8(input.direction)])?;
+            tick = input.tick;
+        }
+
+        Ok(())
+    }
+}
+
+fn direction_to_u8(direction: Direction) -> u8 {
+    Direction::EIGHT
+        .iter()
+        .position(|d| *d == direction)
+        .expect("All directions are listed") as u8
+}
+
+fn invalid(message: impl Into<String>) -> Error {
+    Error::new(ErrorKind::InvalidData, message.into())
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_u8(reader: &mut impl Read) -> Result<u8> {
+    Ok(read_array::<1>(reader)?[0])
+}
+
+fn read_varint(reader: &mut impl Read) -> Result<u32> {
+    let mut value = 0_u32;
+
+    for shift in (0..32).step_by(7) {
+        let byte = read_u8(reader)?;
+        value |= u32::from(byte & 0x7f) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(invalid("Varint overflow"))
+}
+
+fn write_varint(writer: &mut impl Write, mut value: u32) -> Result<()> {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            return writer.write_all(&[byte]);
+        }
+
+        writer.write_all(&[byte | 0x80])?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn replay() -> Replay {
+        let mut replay = Replay::new(
+            1234,
+            1,
+            Config::new((30.0, 20.0), Duration::from_millis(200)),
+        );
+        replay.record(3, 0, Direction::Up);
+        replay.record(3, 0, Direction::Left);
+        replay.record(200, 0, Direction::Down);
+        replay.record(201, 0, Direction::UpRight);
+        replay.ticks = 250;
+        replay.score = 40;
+
+        replay
+    }
+
+    #[test]
+    fn write_read() {
+        let replay = replay();
+        let mut bytes = vec![];
+        replay.write(&mut bytes).unwrap();
+
+        assert_eq!(&bytes[0..5], b"SNKR\x01");
+        assert_eq!(Replay::read(&bytes[..]).unwrap(), replay);
+    }
+
+    #[test]
+    fn write_read_players() {
+        let mut replay = replay().with_players(2);
+        replay.record(250, 1, Direction::DownLeft);
+        let mut bytes = vec![];
+        replay.write(&mut bytes).unwrap();
+
+        assert_eq!(Replay::read(&bytes[..]).unwrap(), replay);
+    }
+
+    #[test]
+    fn write_invalid_player() {
+        let mut bytes = vec![];
+
+        let mut unknown = replay().with_players(2);
+        unknown.record(250, 2, Direction::Up);
+        assert_eq!(
+            unknown.write(&mut bytes).unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+
+        let mut too_many = replay().with_players(20);
+        too_many.record(250, 16, Direction::Up);
+        assert_eq!(
+            too_many.write(&mut bytes).unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn write_read_mode() {
+        let replay = replay().with_mode(GameMode::Survival);
+        let mut bytes = vec![];
+        replay.write(&mut bytes).unwrap();
+
+        assert_eq!(Replay::read(&bytes[..]).unwrap(), replay);
+
+        bytes[15] = 9;
+        assert_eq!(
+            Replay::read(&bytes[..]).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn read_invalid() {
+        let mut bytes = vec![];
+        replay().write(&mut bytes).unwrap();
+
+        let mut version = bytes.clone();
+        version[4] = 99;
+        assert_eq!(
+            Replay::read(&version[..]).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        // 65535×65535
+        let mut bounds = bytes.clone();
+        bounds[16..20].copy_from_slice(&[0xff; 4]);
+        assert_eq!(
+            Replay::read(&bounds[..]).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        assert_eq!(
+            Replay::read(&bytes[..bytes.len() - 1]).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn varint() {
+        for value in [0, 1, 127, 128, 300, u32::MAX].iter() {
+            let mut bytes = vec![];
+            write_varint(&mut bytes, *value).unwrap();
+
+            assert_eq!(read_varint(&mut &bytes[..]).unwrap(), *value);
+        }
+    }
+
+    #[test]
+    fn inputs_at() {
+        let replay = replay();
+
+        let directions = |tick| {
+            replay
+                .inputs_at(tick)
+                .map(|input| input.direction)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(directions(3), vec![Direction::Up, Direction::Left]);
+        assert_eq!(directions(4), vec![]);
+        assert_eq!(directions(200), vec![Direction::Down]);
+        assert_eq!(directions(201), vec![Direction::UpRight]);
+    }
+
+    #[test]
+    fn deterministic() {
+        let mut replay = Replay::new(99, 3, Config::new((30.0, 20.0), Duration::from_millis(200)));
+
+        // Play
+        let mut world = replay.world();
+        let directions = [Direction::Up, Direction::Left, Direction::Down];
+        while !world.is_over() && world.tick() < 500 {
+            if world.tick().is_multiple_of(7) {
+                let direction = directions[world.tick() as usize / 7 % 3];
+                world.steer(direction);
+                replay.record(world.tick(), 0, direction);
+            }
+            world.update();
+        }
+        replay.ticks = world.tick();
+
+        // Replay
+        let mut replayed = replay.world();
+        while replayed.tick() < replay.ticks {
+            replay.steer(&mut replayed);
+            replayed.update();
+        }
+
+        assert_eq!(replayed.is_over(), world.is_over());
+        assert_eq!(replayed.food(), world.food());
+        assert_eq!(
+            replayed.player().body().collect::<Vec<_>>(),
+            world.player().body().collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use super::Game;
+use crate::events::{read_key, read_key_until, KeyCode, Ticker};
+use std::{fmt::Debug, iter, time::Duration};
+
+/// A `Scene` of the game, like the menu or a game being played, on a stack
+/// of `Scenes`.
+///
+/// `Scenes` run the event loop: each frame, the `Scene` on top is rendered,
+/// then handles the keys pressed until the next frame, then is updated.
+pub trait Scene: Debug {
+    /// Returns the time between two updates, or `None` if this `Scene` only
+    /// changes on input.
+    fn period(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Handles `code`, returning a `Transition` if it leaves this `Scene`.
+    fn handle(&mut self, game: &mut Game, code: KeyCode) -> Option<Transition>;
+
+    /// Moves this `Scene` one frame forward, returning a `Transition` if it
+    /// leaves it.
+    ///
+    /// Only called when this `Scene` has a `period`.
+    fn update(&mut self, _: &mut Game) -> Option<Transition> {
+        None
+    }
+
+    /// Draws this `Scene` on the `Screen` of `game`.
+    fn render(&mut self, game: &mut Game);
+
+    /// Handles the `request` of the `Scene` that was above this one.
+    ///
+    /// By default, passes it on to the previous `Scene`.
+    fn request(&mut self, _: &mut Game, request: Request) -> Option<Transition> {
+        Some(Transition::Request(request))
+    }
+}
+
+/// A `Transition` between `Scene`s.
+#[derive(Debug)]
+pub enum Transition {
+    /// Puts a `Scene` on top of the current one.
+    Push(Box<dyn Scene>),
+    /// Goes back to the previous `Scene`.
+    Pop,
+    /// Goes back to the previous `Scene`, which handles the `Request`.
+    Request(Request),
+    /// Replaces the current `Scene`.
+    Replace(Box<dyn Scene>),
+    /// Replaces all the `Scene`s.
+    Reset(Box<dyn Scene>),
+    /// Removes all the `Scene`s.
+    Quit,
+}
+
+/// A `Request` of a `Scene` to the ones below it, like leaving a paused
+/// game for the menu, which ends the game first.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Request {
+    /// Goes back to the menu.
+    Menu,
+    /// Quits the game.
+    Quit,
+}
+
+/// A stack of `Scenes`, the top one running.
+#[derive(Debug)]
+pub struct Scenes {
+    stack: Vec<Box<dyn Scene>>,
+}
+
+impl Scenes {
+    /// Creates `Scenes` starting with `first`.
+    pub fn new(first: Box<dyn Scene>) -> Self {
+        Self { stack: vec![first] }
+    }
+
+    /// Returns the number of `Scene`s on the stack.
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns whether no `Scene` is left.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Applies `transition` to the stack, clearing the screen for the next
+    /// `Scene`.
+    pub fn apply(&mut self, game: &mut Game, transition: Transition) {
+        match transition {
+            Transition::Push(scene) => self.stack.push(scene),
+            Transition::Pop => {
+                self.stack.pop();
+            }
+            Transition::Request(request) => {
+                self.stack.pop();
+                let next = self
+                    .stack
+                    .last_mut()
+                    .and_then(|scene| scene.request(game, request));
+                if let Some(next) = next {
+                    self.apply(game, next);
+                }
+            }
+            Transition::Replace(scene) => {
+                self.stack.pop();
+                self.stack.push(scene);
+            }
+            Transition::Reset(scene) => {
+                self.stack.clear();
+                self.stack.push(scene);
+            }
+            Transition::Quit => self.stack.clear(),
+        }
+
+        game.screen.clear();
+    }
+
+    /// Runs a frame of the `Scene` on top of the stack: renders it, handles
+    /// `keys` until one makes a `Transition`, then updates it if it has a
+    /// `period`.
+    ///
+    /// Returns whether the frame made a `Transition`.
+    pub fn frame(&mut self, game: &mut Game, keys: impl IntoIterator<Item = KeyCode>) -> bool {
+        let scene = match self.stack.last_mut() {
+            Some(scene) => scene,
+            None => return false,
+        };
+
+        scene.render(game);
+        let mut transition = keys.into_iter().find_map(|code| scene.handle(game, code));
+        if transition.is_none() && scene.period().is_some() {
+            transition = scene.update(game);
+        }
+
+        match transition {
+            Some(transition) => {
+                self.apply(game, transition);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Runs the `Scene` on top of the stack, and the next ones, until none is
+    /// left.
+    ///
+    /// `Scene`s with a `period` get the keys pressed until their next frame,
+    /// the others wait for a key each frame.
+    pub fn run(&mut self, game: &mut Game) {
+        let mut ticker: Option<Ticker> = None;
+
+        game.screen.clear();
+        while let Some(scene) = self.stack.last() {
+            match scene.period() {
+                Some(period) => {
+                    let deadline = ticker.get_or_insert_with(|| Ticker::new(period)).deadline();
+
+                    if self.frame(game, iter::from_fn(|| read_key_until(deadline))) {
+                        ticker = None;
+                    } else if let Some(ticker) = &mut ticker {
+                        ticker.tick(period);
+                    }
+                }
+                None => {
+                    ticker = None;
+                    self.frame(game, iter::once_with(read_key));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+    use pretty_assertions::assert_eq;
+
+    // Told apart by their `Debug` output
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Named(&'static str);
+
+    impl Scene for Named {
+        fn handle(&mut self, _: &mut Game, _: KeyCode) -> Option<Transition> {
+            Some(Transition::Quit)
+        }
+
+        fn render(&mut self, _: &mut Game) {}
+
+        fn request(&mut self, _: &mut Game, request: Request) -> Option<Transition> {
+            match (self.0, request) {
+                ("menu", Request::Menu) => None,
+                _ => Some(Transition::Request(request)),
+            }
+        }
+    }
+
+    fn named(name: &'static str) -> Box<dyn Scene> {
+        Box::new(Named(name))
+    }
+
+    #[test]
+    fn transitions() {
+        let (mut game, _) = testing::game(20, 10);
+        let mut scenes = Scenes::new(named("menu"));
+
+        scenes.apply(&mut game, Transition::Push(named("play")));
+        scenes.apply(&mut game, Transition::Push(named("pause")));
+        assert_eq!(scenes.len(), 3);
+
+        scenes.apply(&mut game, Transition::Pop);
+        scenes.apply(&mut game, Transition::Replace(named("game over")));
+        assert_eq!(
+            format!("{:?}", scenes),
+            r#"Scenes { stack: [Named("menu"), Named("game over")] }"#
+        );
+
+        scenes.apply(&mut game, Transition::Reset(named("welcome")));
+        assert_eq!(
+            format!("{:?}", scenes),
+            r#"Scenes { stack: [Named("welcome")] }"#
+        );
+
+        scenes.apply(&mut game, Transition::Quit);
+        assert!(scenes.is_empty());
+    }
+
+    #[test]
+    fn requests() {
+        let (mut game, _) = testing::game(20, 10);
+        let mut scenes = Scenes::new(named("menu"));
+
+        scenes.apply(&mut game, Transition::Push(named("play")));
+        scenes.apply(&mut game, Transition::Push(named("pause")));
+        scenes.apply(&mut game, Transition::Request(Request::Menu));
+        assert_eq!(
+            format!("{:?}", scenes),
+            r#"Scenes { stack: [Named("menu")] }"#
+        );
+
+        scenes.apply(&mut game, Transition::Push(named("play")));
+        scenes.apply(&mut game, Transition::Request(Request::Quit));
+        assert!(scenes.is_empty());
+    }
+}
@@ -0,0 +1,349 @@
+use super::{Game, GameMode, HighScore, Outcome, PlayScene, Request, Scene, Transition};
+use crate::{
+    consts::{MENU_FPS, WELCOME_FPS},
+    events::KeyCode,
+    screen::{
+        GameOver,
+        GameOverAction,
+        Leaderboard,
+        Menu,
+        MenuAction,
+        NameEntry,
+        NameEntryAction,
+        Pause,
+        PauseAction,
+        Settings,
+        Statistics,
+        Trophies,
+        Welcome,
+        WelcomeAction,
+    },
+};
+use std::time::Duration;
+
+/// The `WelcomeScene` greets the player, before the menu.
+#[derive(Debug)]
+pub struct WelcomeScene {
+    welcome: Welcome,
+}
+
+/// The `MenuScene` lets the player choose a level and mode, and other
+/// screens.
+#[derive(Debug)]
+pub struct MenuScene {
+    menu: Menu,
+}
+
+/// The `TrophiesScene` lists the achievements.
+#[derive(Debug)]
+pub struct TrophiesScene {
+    trophies: Trophies,
+}
+
+/// The `StatsScene` shows the lifetime stats.
+#[derive(Debug)]
+pub struct StatsScene {
+    statistics: Statistics,
+}
+
+/// The `HighScoresScene` lists the high scores.
+#[derive(Debug)]
+pub struct HighScoresScene {
+    leaderboard: Leaderboard,
+}
+
+/// The `SettingsScene` changes the options of the next games.
+#[derive(Debug)]
+pub struct SettingsScene {
+    settings: Settings,
+}
+
+/// The `PauseScene` suspends the game below it, which it asks to end when
+/// leaving for the menu or quitting.
+#[derive(Debug)]
+pub struct PauseScene {
+    pause: Pause,
+}
+
+/// The `NameEntryScene` asks for the name of a new high score, then makes
+/// the `Transition` that was due at the end of the game.
+#[derive(Debug)]
+pub struct NameEntryScene {
+    name_entry: NameEntry,
+    score:      u32,
+    level:      u8,
+    mode:       GameMode,
+    then:       Option<Transition>,
+}
+
+/// The `GameOverScene` sums up a finished game, and offers to play again.
+#[derive(Debug)]
+pub struct GameOverScene {
+    game_over: GameOver,
+    outcome:   Outcome,
+    id:        u8,
+    mode:      GameMode,
+    seed:      u64,
+}
+
+impl WelcomeScene {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            welcome: Welcome::new(game.screen.width(), game.screen.height()),
+        }
+    }
+}
+
+impl Scene for WelcomeScene {
+    fn period(&self) -> Option<Duration> {
+        Some(Duration::from_millis(1000 / WELCOME_FPS))
+    }
+
+    fn handle(&mut self, game: &mut Game, code: KeyCode) -> Option<Transition> {
+        match self
+            .welcome
+            .handle(game.screen.out(), &game.options.theme, code)?
+        {
+            WelcomeAction::Quit => Some(Transition::Quit),
+            WelcomeAction::Continue => {
+                let snake_y =
+                    Some(self.welcome.snake_y()).filter(|_| self.welcome.is_snake_blinking());
+
+                Some(Transition::Replace(Box::new(MenuScene::new(game, snake_y))))
+            }
+        }
+    }
+
+    fn update(&mut self, _: &mut Game) -> Option<Transition> {
+        self.welcome.update();
+        None
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        self.welcome.render(game.screen.out(), &game.options.theme);
+    }
+}
+
+impl MenuScene {
+    /// Creates a new `MenuScene`, where the snake slides from `snake_y_anim`
+    /// if any.
+    pub fn new(game: &Game, snake_y_anim: Option<u16>) -> Self {
+        let mut menu = Menu::new(game.screen.width(), game.screen.height());
+        if let Some(snake_y) = snake_y_anim {
+            menu.slide_from(snake_y);
+        }
+
+        Self { menu }
+    }
+}
+
+impl Scene for MenuScene {
+    fn period(&self) -> Option<Duration> {
+        Some(Duration::from_millis(1000 / MENU_FPS)).filter(|_| self.menu.is_sliding())
+    }
+
+    fn handle(&mut self, game: &mut Game, code: KeyCode) -> Option<Transition> {
+        let scene: Box<dyn Scene> = match self.menu.handle(code)? {
+            MenuAction::Level(id, mode) => {
+                let seed = game.options.seed.unwrap_or_else(rand::random);
+
+                Box::new(PlayScene::new(game, id, mode, seed))
+            }
+            MenuAction::Achievements => Box::new(TrophiesScene::new(game)),
+            MenuAction::Stats => Box::new(StatsScene::new(game)),
+            MenuAction::HighScores => Box::new(HighScoresScene::new(game)),
+            MenuAction::Settings => Box::new(SettingsScene::new(game)),
+            MenuAction::Quit => return Some(Transition::Quit),
+        };
+
+        Some(Transition::Push(scene))
+    }
+
+    fn update(&mut self, _: &mut Game) -> Option<Transition> {
+        self.menu.update();
+        None
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        self.menu.render(game.screen.out(), &game.options.theme);
+    }
+
+    /// Stays on the menu, or quits.
+    fn request(&mut self, _: &mut Game, request: Request) -> Option<Transition> {
+        match request {
+            Request::Menu => None,
+            Request::Quit => Some(Transition::Quit),
+        }
+    }
+}
+
+impl TrophiesScene {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            trophies: Trophies::new(game.screen.width(), game.screen.height()),
+        }
+    }
+}
+
+impl Scene for TrophiesScene {
+    fn handle(&mut self, _: &mut Game, _: KeyCode) -> Option<Transition> {
+        Some(Transition::Pop)
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        self.trophies
+            .render(game.screen.out(), &game.achievements, &game.options.theme);
+    }
+}
+
+impl StatsScene {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            statistics: Statistics::new(game.screen.width(), game.screen.height()),
+        }
+    }
+}
+
+impl Scene for StatsScene {
+    fn handle(&mut self, _: &mut Game, _: KeyCode) -> Option<Transition> {
+        Some(Transition::Pop)
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        self.statistics.render(game.screen.out(), &game.stats);
+    }
+}
+
+impl HighScoresScene {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            leaderboard: Leaderboard::new(game.screen.width(), game.screen.height()),
+        }
+    }
+}
+
+impl Scene for HighScoresScene {
+    fn handle(&mut self, _: &mut Game, _: KeyCode) -> Option<Transition> {
+        Some(Transition::Pop)
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        self.leaderboard
+            .render(game.screen.out(), &game.high_scores);
+    }
+}
+
+impl SettingsScene {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            settings: Settings::new(game.screen.width(), game.screen.height()),
+        }
+    }
+}
+
+impl Scene for SettingsScene {
+    fn handle(&mut self, game: &mut Game, code: KeyCode) -> Option<Transition> {
+        Some(Transition::Pop).filter(|_| self.settings.handle(code, &mut game.options))
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        self.settings.render(game.screen.out(), &game.options);
+    }
+}
+
+impl PauseScene {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            pause: Pause::new(game.screen.width(), game.screen.height()),
+        }
+    }
+}
+
+impl Scene for PauseScene {
+    fn handle(&mut self, _: &mut Game, code: KeyCode) -> Option<Transition> {
+        Some(match self.pause.handle(code)? {
+            PauseAction::Resume => Transition::Pop,
+            PauseAction::Menu => Transition::Request(Request::Menu),
+            PauseAction::Quit => Transition::Request(Request::Quit),
+        })
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        self.pause.render(game.screen.out(), &game.options.theme);
+    }
+}
+
+impl NameEntryScene {
+    /// Creates a new `NameEntryScene` for the high score `score` on the level
+    /// with the given `id` in `mode`, that makes `then` once done.
+    pub fn new(game: &Game, score: u32, id: u8, mode: GameMode, then: Transition) -> Self {
+        let name_entry = NameEntry::new(
+            game.screen.width(),
+            game.screen.height(),
+            score,
+            game.high_scores.last_name(),
+        );
+
+        Self {
+            name_entry,
+            score,
+            level: id,
+            mode,
+            then: Some(then),
+        }
+    }
+}
+
+impl Scene for NameEntryScene {
+    fn handle(&mut self, game: &mut Game, code: KeyCode) -> Option<Transition> {
+        if let NameEntryAction::Save(name) = self.name_entry.handle(code)? {
+            game.add_high_score(HighScore {
+                name,
+                score: self.score,
+                level: self.level,
+                mode: self.mode,
+            });
+        }
+
+        self.then.take()
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        self.name_entry.render(game.screen.out());
+    }
+}
+
+impl GameOverScene {
+    /// Creates a new `GameOverScene` for the game on the level with the given
+    /// `id` in `mode` from `seed`, that ended with `outcome`.
+    pub fn new(game: &Game, outcome: Outcome, id: u8, mode: GameMode, seed: u64) -> Self {
+        Self {
+            game_over: GameOver::new(game.screen.width(), game.screen.height()),
+            outcome,
+            id,
+            mode,
+            seed,
+        }
+    }
+}
+
+impl Scene for GameOverScene {
+    fn handle(&mut self, game: &mut Game, code: KeyCode) -> Option<Transition> {
+        let action = self.game_over.handle(code)?;
+        let retry = |game: &Game, seed| {
+            Transition::Replace(Box::new(PlayScene::new(game, self.id, self.mode, seed)))
+        };
+
+        Some(match action {
+            GameOverAction::Retry => retry(game, self.seed),
+            GameOverAction::RetryNewSeed => retry(game, rand::random()),
+            GameOverAction::Menu => Transition::Pop,
+            GameOverAction::Quit => Transition::Quit,
+        })
+    }
+
+    fn render(&mut self, game: &mut Game) {
+        self.game_over
+            .render(game.screen.out(), &self.outcome, &game.options.theme);
+    }
+}
@@ -0,0 +1,194 @@
+use super::{GameMode, LEVELS};
+use std::{
+    cmp::Reverse,
+    fmt::{Display, Error, Formatter},
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+/// The file where high scores are saved, in the game's directory.
+const FILE: &str = "scores";
+
+/// The number of entries of the table.
+pub const HIGH_SCORES: usize = 10;
+
+/// The max length of a name, in characters.
+pub const NAME_MAX: usize = 12;
+
+/// An entry of the `HighScores` table.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct HighScore {
+    pub name:  String,
+    pub score: u32,
+    /// The id of the `Level`.
+    pub level: u8,
+    pub mode:  GameMode,
+}
+
+/// The `HighScores` table, best first, saved across games with the last
+/// name entered.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct HighScores {
+    entries:   Vec<HighScore>,
+    last_name: String,
+}
+
+impl HighScores {
+    /// Loads the `HighScores` saved in `dir`, empty when never saved.
+    ///
+    /// Invalid lines are ignored.
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        match fs::read_to_string(dir.join(FILE)) {
+            Ok(text) => Ok(Self::parse(&text)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Saves the `HighScores` in `dir`.
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        fs::write(dir.join(FILE), self.to_string())
+    }
+
+    /// Returns the entries, best first.
+    pub fn entries(&self) -> &[HighScore] {
+        &self.entries
+    }
+
+    /// Returns the last name entered.
+    pub fn last_name(&self) -> &str {
+        &self.last_name
+    }
+
+    /// Returns whether `score` enters the table.
+    pub fn qualifies(&self, score: u32) -> bool {
+        score > 0
+            && (self.entries.len() < HIGH_SCORES
+                || self.entries.iter().any(|entry| entry.score < score))
+    }
+
+    /// Inserts `entry` in the table if it qualifies, remembering its name.
+    ///
+    /// Returns its rank, from 0.
+    pub fn insert(&mut self, entry: HighScore) -> Option<usize> {
+        self.last_name = entry.name.clone();
+        if !self.qualifies(entry.score) {
+            return None;
+        }
+
+        // After the entries of the same score, which were there first
+        let rank = self
+            .entries
+            .iter()
+            .position(|other| other.score < entry.score)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(rank, entry);
+        self.entries.truncate(HIGH_SCORES);
+
+        Some(rank)
+    }
+
+    fn parse(text: &str) -> Self {
+        let mut scores = Self::default();
+
+        for line in text.lines() {
+            let fields = line.split('\t').collect::<Vec<_>>();
+
+            match fields[..] {
+                ["name", name] => scores.last_name = name.to_string(),
+                ["score", score, level, mode, name] => {
+                    let score = score.parse().ok();
+                    let level = level
+                        .parse()
+                        .ok()
+                        .filter(|level| usize::from(*level) < LEVELS.len());
+                    let mode = mode.parse().ok().and_then(GameMode::get);
+
+                    if let (Some(score), Some(level), Some(mode)) = (score, level, mode) {
+                        scores.entries.push(HighScore {
+                            name: name.to_string(),
+                            score,
+                            level,
+                            mode,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        scores.entries.sort_by_key(|entry| Reverse(entry.score));
+        scores.entries.truncate(HIGH_SCORES);
+
+        scores
+    }
+}
+
+impl Display for HighScores {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        writeln!(f, "name\t{}", self.last_name)?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "score\t{}\t{}\t{}\t{}",
+                entry.score,
+                entry.level,
+                entry.mode.id(),
+                entry.name
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn entry(name: &str, score: u32) -> HighScore {
+        HighScore {
+            name: name.to_string(),
+            score,
+            level: 1,
+            mode: GameMode::Classic,
+        }
+    }
+
+    #[test]
+    fn insert() {
+        let mut scores = HighScores::default();
+        assert!(!scores.qualifies(0));
+
+        for i in 0..HIGH_SCORES as u32 {
+            assert_eq!(scores.insert(entry("a", 10 + i * 10)), Some(0));
+        }
+        assert!(!scores.qualifies(10));
+        assert_eq!(scores.insert(entry("b", 10)), None);
+        assert_eq!(scores.last_name(), "b");
+
+        assert_eq!(scores.insert(entry("c", 50)), Some(6));
+        assert_eq!(scores.entries().len(), HIGH_SCORES);
+        assert_eq!(scores.entries()[5].score, 50);
+        assert_eq!(scores.entries()[6], entry("c", 50));
+        assert_eq!(scores.entries()[HIGH_SCORES - 1].score, 20);
+    }
+
+    #[test]
+    fn parse() {
+        let mut scores = HighScores::default();
+        scores.insert(entry("Sñake 🐍", 30));
+        scores.insert(HighScore {
+            mode: GameMode::Zen,
+            ..entry("Other", 40)
+        });
+
+        assert_eq!(HighScores::parse(&scores.to_string()), scores);
+        assert_eq!(
+            HighScores::parse("score\t10\t99\t0\tBad level\nscore\tten\t1\t0\tBad score\n"),
+            HighScores::default()
+        );
+    }
+}
@@ -1,19 +1,105 @@
-use crate::physics::{Coord2D, Direction, Duration, Moving, Point, Speed2D};
-use crossterm::cursor::MoveTo;
+use crate::{
+    color::{Rgb, PALETTE},
+    physics::{Direction, Duration, Moving, Point, Speed2D},
+    theme::{Draw, SnakeGlyphs, Theme},
+    width,
+};
+use crossterm::style::{ResetColor, SetForegroundColor};
 use std::{
     collections::VecDeque,
-    fmt::{Display, Error, Formatter},
+    f64::consts::SQRT_2,
+    fmt::{Error, Formatter},
 };
 
+/// Why a `Snake` died.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Death {
+    /// It bit itself.
+    Itself,
+    /// It hit a wall.
+    Wall,
+    /// It hit a rock.
+    Rock,
+    /// It hit another snake.
+    Snake,
+    /// Its player left the game.
+    Left,
+}
+
+impl Death {
+    /// All the causes of death.
+    pub const ALL: [Death; 5] = [
+        Self::Itself,
+        Self::Wall,
+        Self::Rock,
+        Self::Snake,
+        Self::Left,
+    ];
+
+    /// Returns a short description of this `Death`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Itself => "Bit itself",
+            Self::Wall => "Hit a wall",
+            Self::Rock => "Hit a rock",
+            Self::Snake => "Hit a snake",
+            Self::Left => "Left",
+        }
+    }
+}
+
+/// The `Skin` of a `Snake`, drawn with the glyphs of a theme.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Skin {
+    Player,
+    Opponent,
+}
+
+impl Skin {
+    /// Returns the glyphs of this `Skin` in `theme`.
+    pub fn glyphs(self, theme: &Theme) -> SnakeGlyphs {
+        match self {
+            Self::Player => theme.player,
+            Self::Opponent => theme.opponent,
+        }
+    }
+
+    /// Returns the glyph in `theme` of the cell at `index` of a body of
+    /// `len` cells, head first.
+    pub fn glyph(self, theme: &Theme, index: usize, len: usize) -> &'static str {
+        let glyphs = self.glyphs(theme);
+
+        if index == 0 {
+            glyphs.head
+        } else if index + 1 == len {
+            glyphs.tail
+        } else {
+            glyphs.body
+        }
+    }
+
+    /// Returns the color of the cell at `index` of a body of `len` cells,
+    /// fading from the head to the tail.
+    pub fn color(self, index: usize, len: usize) -> Rgb {
+        match self {
+            Self::Player => PALETTE.player.at(index, len),
+            Self::Opponent => PALETTE.opponent.at(index, len),
+        }
+    }
+}
+
 /// The infamous `Snake`
 #[derive(Debug)]
 pub struct Snake {
-    position:        Point,
+    progress:        f64,
     body:            VecDeque<Point>,
     direction:       Direction,
     draft_direction: Direction,
     speed:           Speed2D,
     last_tail:       Option<Point>,
+    skin:            Skin,
+    death:           Option<Death>,
+    eaten:           u32,
 }
 
 impl Snake {
@@ -22,80 +108,301 @@ impl Snake {
         direction: Direction,
         speed: impl Into<Speed2D>,
     ) -> Self {
-        let position = position.into();
         let mut body = VecDeque::new();
-        body.push_front(position.round());
+        body.push_front(position.into().round());
         let last_tail = None;
 
         Self {
-            position,
+            progress: 0.0,
             body,
             direction,
             draft_direction: direction,
             speed: speed.into(),
             last_tail,
+            skin: Skin::Player,
+            death: None,
+            eaten: 0,
         }
     }
 
+    /// Creates a still `Snake` of `body`, head first, as seen in a
+    /// `Snapshot`.
+    pub fn from_body(body: impl IntoIterator<Item = Point>, skin: Skin) -> Self {
+        Self {
+            body: body.into_iter().collect(),
+            ..Self::new(
+                Point::new(0.0, 0.0),
+                Direction::Right,
+                Speed2D::from_per_sec((0.0, 0.0)),
+            )
+        }
+        .with_skin(skin)
+    }
+
+    /// Sets the `Skin` used to draw the body.
+    pub fn with_skin(mut self, skin: Skin) -> Self {
+        self.skin = skin;
+        self
+    }
+
+    pub fn skin(&self) -> Skin {
+        self.skin
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns the `Direction` taken at the next update.
+    pub fn draft_direction(&self) -> Direction {
+        self.draft_direction
+    }
+
     pub fn set_direction(&mut self, direction: Direction) {
         if self.direction != -direction {
             self.draft_direction = direction
         }
     }
 
+    /// Returns the speed, in cells per second.
+    pub fn speed(&self) -> f64 {
+        self.speed.as_per_sec().x
+    }
+
+    /// Sets the speed, in cells per second.
+    pub fn set_speed(&mut self, per_sec: f64) {
+        self.speed = Speed2D::from_per_sec((per_sec, per_sec));
+    }
+
     pub fn commit_direction(&mut self) {
         self.direction = self.draft_direction;
     }
 
     pub fn head(&self) -> Point {
-        *self.body.get(0).expect("Snake have no body")
+        *self.body.front().expect("Snake have no body")
     }
 
-    pub fn grow_head(&mut self, i: u8) {
-        for _ in 0..i {
-            let head = self.head();
-            let direction: Point = self.direction.into();
-            self.body.push_front(head + direction);
-        }
+    pub fn body(&self) -> impl Iterator<Item = &Point> {
+        self.body.iter()
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.death.is_none()
+    }
+
+    /// Returns why this `Snake` died, if dead.
+    pub fn death(&self) -> Option<Death> {
+        self.death
+    }
+
+    pub fn kill(&mut self, death: Death) {
+        self.death.get_or_insert(death);
+    }
+
+    /// Returns how many foods this `Snake` has eaten.
+    pub fn eaten(&self) -> u32 {
+        self.eaten
+    }
+
+    /// Eats a food, growing by one cell.
+    pub fn eat(&mut self) {
+        self.eaten += 1;
+        self.grow_tail();
+    }
+
+    /// Moves the head to `cell`, leaving to `direction`, while the rest of
+    /// the body stays behind and follows.
+    pub fn teleport(&mut self, cell: Point, direction: Direction) {
+        self.body.pop_front();
+        self.body.push_front(cell);
+        self.direction = direction;
+        self.draft_direction = direction;
     }
 
     pub fn grow_tail(&mut self) {
         if let Some(last_tail) = self.last_tail.take() {
-            self.body.push_front(last_tail);
+            self.body.push_back(last_tail);
         }
     }
 
-    pub fn shrink(&mut self, i: u8) {
-        for _ in 0..i {
-            self.last_tail = self.body.pop_back();
+    /// Moves forward for `duration`, returning the number of cells to
+    /// `step` by.
+    ///
+    /// Diagonal cells are √2 away, so they take longer to reach. The
+    /// fraction of a cell left is kept for the next call.
+    pub fn advance(&mut self, duration: Duration) -> u32 {
+        let mut cells = self.speed() * duration.as_secs_f64();
+        if self.direction.is_diagonal() {
+            cells /= SQRT_2;
         }
+        self.progress += cells;
+
+        let cells = self.progress.floor();
+        self.progress -= cells;
+        cells as u32
+    }
+
+    /// Steps the head one cell forward, the body following.
+    pub fn step(&mut self) {
+        let head = self.head() + Point::from(self.direction);
+
+        self.body.push_front(head);
+        self.last_tail = self.body.pop_back();
     }
 
     pub fn contains(&self, position: impl Into<Point>) -> bool {
         self.body.contains(&position.into())
     }
+
+    /// Returns whether the head overlaps the rest of the body.
+    pub fn bites_itself(&self) -> bool {
+        let head = self.head();
+
+        self.body.iter().skip(1).any(|point| *point == head)
+    }
+
+    /// Wraps the `Snake` into `bounds`, as on a torus.
+    pub fn wrap(&mut self, bounds: Point) {
+        for point in &mut self.body {
+            *point = point.wrap(bounds);
+        }
+        if let Some(last_tail) = &mut self.last_tail {
+            *last_tail = last_tail.wrap(bounds);
+        }
+    }
 }
 
 impl Moving for Snake {
     fn r#move(&mut self, duration: Duration) {
-        let prev_position = self.position.round();
-        (&mut self.position, self.speed, self.direction).r#move(duration);
-
-        let position = self.position.round();
-        if position != prev_position {
-            let grow = (position - prev_position).length().round() as u8;
-            self.grow_head(grow);
-            self.shrink(grow);
+        for _ in 0..self.advance(duration) {
+            self.step();
         }
     }
 }
 
-impl Display for Snake {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        for point in &self.body {
-            write!(f, "{}🐍", MoveTo(point.x as u16 * 2, point.y as u16))?;
+impl Draw for Snake {
+    fn draw(&self, f: &mut Formatter, theme: &Theme) -> Result<(), Error> {
+        let len = self.body.len();
+
+        for (i, point) in self.body.iter().enumerate() {
+            write!(
+                f,
+                "{}{}{}",
+                width::move_to(*point),
+                SetForegroundColor(self.skin.color(i, len).color()),
+                self.skin.glyph(theme, i, len)
+            )?;
+        }
+
+        write!(f, "{}", ResetColor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn snake() -> Snake {
+        Snake::new(
+            (2.0, 2.0),
+            Direction::Right,
+            Speed2D::from_per_sec((1.0, 1.0)),
+        )
+    }
+
+    #[test]
+    fn set_direction() {
+        let mut snake = snake();
+
+        snake.set_direction(Direction::Left);
+        snake.commit_direction();
+        assert_eq!(snake.direction(), Direction::Right);
+
+        snake.set_direction(Direction::Up);
+        snake.commit_direction();
+        assert_eq!(snake.direction(), Direction::Up);
+    }
+
+    #[test]
+    fn eat() {
+        let mut snake = snake();
+
+        snake.r#move(Duration::from_secs(1));
+        assert_eq!(snake.body().cloned().collect::<Vec<_>>(), vec![Point::new(
+            3.0, 2.0
+        )]);
+
+        snake.eat();
+        assert_eq!(snake.eaten(), 1);
+        assert_eq!(snake.body().cloned().collect::<Vec<_>>(), vec![
+            Point::new(3.0, 2.0),
+            Point::new(2.0, 2.0)
+        ]);
+    }
+
+    #[test]
+    fn grow_tail() {
+        let mut snake = snake();
+
+        for _ in 0..2 {
+            snake.r#move(Duration::from_secs(1));
+            snake.grow_tail();
         }
+        assert_eq!(snake.head(), Point::new(4.0, 2.0));
+        assert_eq!(snake.body().cloned().collect::<Vec<_>>(), vec![
+            Point::new(4.0, 2.0),
+            Point::new(3.0, 2.0),
+            Point::new(2.0, 2.0)
+        ]);
+    }
+
+    #[test]
+    fn advance() {
+        let mut snake = snake();
+        snake.set_speed(2.5);
+
+        assert_eq!(snake.advance(Duration::from_millis(500)), 1);
+        assert_eq!(snake.advance(Duration::from_millis(500)), 1);
+        assert_eq!(snake.advance(Duration::from_secs(1)), 3);
+
+        snake.set_speed(10.0);
+        snake.set_direction(Direction::UpRight);
+        snake.commit_direction();
+        assert_eq!(
+            (0..10)
+                .map(|_| snake.advance(Duration::from_secs(1)))
+                .sum::<u32>(),
+            70
+        );
+    }
+
+    #[test]
+    fn teleport() {
+        let mut snake = snake();
+        snake.r#move(Duration::from_secs(1));
+        snake.eat();
+
+        snake.teleport(Point::new(8.0, 8.0), Direction::Up);
+        assert_eq!(snake.direction(), Direction::Up);
+        assert_eq!(snake.body().cloned().collect::<Vec<_>>(), vec![
+            Point::new(8.0, 8.0),
+            Point::new(2.0, 2.0)
+        ]);
+
+        snake.r#move(Duration::from_secs(1));
+        assert_eq!(snake.body().cloned().collect::<Vec<_>>(), vec![
+            Point::new(8.0, 7.0),
+            Point::new(8.0, 8.0)
+        ]);
+    }
+
+    #[test]
+    fn wrap() {
+        let mut snake = snake();
 
-        Ok(())
+        snake.r#move(Duration::from_secs(2));
+        snake.wrap(Point::new(4.0, 4.0));
+        assert_eq!(snake.head(), Point::new(0.0, 2.0));
     }
 }
@@ -0,0 +1,158 @@
+use super::{draw, Food, Skin, Snake, Terrain, Tile};
+use crate::{
+    physics::{PathFragment, Point},
+    theme::{Draw, Theme},
+};
+use std::{
+    fmt::{Error, Formatter},
+    iter::{self, Empty},
+};
+
+/// A `Snapshot` of a `Snake`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SnakeSnapshot {
+    /// The `Skin` used to draw the body.
+    pub skin:  Skin,
+    /// Whether the snake is alive.
+    pub alive: bool,
+    /// The score of the snake.
+    pub score: u32,
+    /// The cells of the body, head first.
+    pub body:  Vec<Point>,
+}
+
+/// A `Snapshot` of a `World`, enough to draw it.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Snapshot {
+    /// The tick of the `World`.
+    pub tick:    u32,
+    /// The cell of the food.
+    pub food:    Point,
+    /// The cells that are not floor, with their `Tile`.
+    pub terrain: Vec<(Point, Tile)>,
+    /// The snakes, dead or alive.
+    pub snakes:  Vec<SnakeSnapshot>,
+}
+
+/// A `View` rebuilds the `World` a `Snapshot` was taken from, enough to draw
+/// it like the `World` itself.
+#[derive(Debug)]
+pub struct View {
+    terrain: Terrain,
+    snakes:  Vec<Snake>,
+    food:    Food<Empty<PathFragment>>,
+}
+
+impl Snapshot {
+    /// Returns whether all the cells of this `Snapshot` are within `bounds`.
+    pub fn fits(&self, bounds: Point) -> bool {
+        let within =
+            |cell: &Point| (0.0..bounds.x).contains(&cell.x) && (0.0..bounds.y).contains(&cell.y);
+
+        within(&self.food)
+            && self.terrain.iter().all(|(cell, _)| within(cell))
+            && self.snakes.iter().flat_map(|snake| &snake.body).all(within)
+    }
+
+    /// Returns the `View` of this `Snapshot` of a `World` of `bounds` cells.
+    pub fn view(&self, bounds: Point) -> View {
+        let mut terrain = Terrain::new(bounds);
+        for (cell, tile) in &self.terrain {
+            terrain.set(*cell, *tile);
+        }
+
+        View {
+            terrain,
+            snakes: self
+                .snakes
+                .iter()
+                .filter(|snake| snake.alive && !snake.body.is_empty())
+                .map(|snake| Snake::from_body(snake.body.iter().copied(), snake.skin))
+                .collect(),
+            food: Food::new(self.food, iter::empty()),
+        }
+    }
+}
+
+impl Draw for View {
+    fn draw(&self, f: &mut Formatter, theme: &Theme) -> Result<(), Error> {
+        draw(f, theme, &self.terrain, &self.snakes, &self.food)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ai::Difficulty,
+        backend::MemoryBackend,
+        color::PALETTE,
+        game::{Config, Level, Obstacle, SpeedCurve, World},
+        physics::Point,
+        theme::{Draw, EMOJI, THEMES},
+        width::CELL_WIDTH,
+    };
+    use pretty_assertions::assert_eq;
+    use std::{io::Write, time::Duration};
+
+    fn world() -> World {
+        let level = Level {
+            name:      "Test",
+            opponents: &[Difficulty::Easy],
+            obstacles: &[Obstacle::Border],
+            speed:     SpeedCurve::constant(10.0),
+            goal:      None,
+        };
+        World::new(
+            Config::new((20.0, 10.0), Duration::from_millis(100)),
+            42,
+            &level,
+        )
+    }
+
+    #[test]
+    fn view() {
+        let mut world = world();
+        for _ in 0..5 {
+            world.update();
+        }
+
+        let view = world.snapshot().view(world.bounds());
+        for theme in THEMES.iter() {
+            assert_eq!(
+                view.themed(theme).to_string(),
+                world.themed(theme).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn fits() {
+        let world = world();
+        let mut snapshot = world.snapshot();
+        assert!(snapshot.fits(world.bounds()));
+
+        snapshot.snakes[0].body.push(Point::new(40000.0, 2.0));
+        assert!(!snapshot.fits(world.bounds()));
+    }
+
+    #[test]
+    fn colored() {
+        let world = world();
+        let mut backend = MemoryBackend::new(40, 10);
+        let view = world.snapshot().view(world.bounds());
+        write!(backend, "{}", view.themed(&EMOJI)).unwrap();
+
+        let fg = |cell: Point| {
+            backend
+                .cell(cell.x as u16 * CELL_WIDTH, cell.y as u16)
+                .and_then(|cell| cell.style.fg)
+        };
+        assert_eq!(fg(world.food()), Some(PALETTE.food.color()));
+        assert_eq!(fg(Point::new(0.0, 0.0)), Some(PALETTE.wall.color()));
+        assert_eq!(fg(world.player().head()), Some(PALETTE.player.head.color()));
+        assert_eq!(
+            fg(world.snakes()[1].head()),
+            Some(PALETTE.opponent.head.color())
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use super::{Death, World, LEVELS};
+use std::{
+    fmt::{Display, Error, Formatter},
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+    time::Duration,
+};
+
+/// The file where stats are saved, in the game's directory.
+const FILE: &str = "stats";
+
+/// The lifetime `Stats` of the players, saved across games.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct Stats {
+    /// The number of games played.
+    pub games:   u32,
+    /// The number of foods eaten.
+    pub eaten:   u64,
+    /// The length of the longest snake.
+    pub longest: u32,
+    /// The time played.
+    pub time:    Duration,
+    /// The number of deaths, by cause in `Death::ALL`.
+    pub deaths:  [u32; Death::ALL.len()],
+    /// The number of games and the total score, by level.
+    pub levels:  [(u32, u64); LEVELS.len()],
+}
+
+impl Stats {
+    /// Loads the `Stats` saved in `dir`, empty when never saved.
+    ///
+    /// Unknown or invalid lines are ignored.
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        match fs::read_to_string(dir.join(FILE)) {
+            Ok(text) => Ok(Self::parse(&text)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Saves the `Stats` in `dir`.
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        fs::write(dir.join(FILE), self.to_string())
+    }
+
+    /// Adds a finished game on the level with the given `id` to the `Stats`.
+    ///
+    /// Each human player counts as a game.
+    pub fn record(&mut self, level: u8, world: &World) {
+        let time = world.elapsed();
+
+        for (i, snake) in world.snakes()[..world.players()].iter().enumerate() {
+            self.games += 1;
+            self.eaten += u64::from(snake.eaten());
+            self.longest = self.longest.max(snake.body().count() as u32);
+            self.time += time;
+
+            if let Some(death) = snake.death() {
+                self.deaths[death_index(death)] += 1;
+            }
+
+            if let Some((games, score)) = self.levels.get_mut(usize::from(level)) {
+                *games += 1;
+                *score += u64::from(world.score_of(i));
+            }
+        }
+    }
+
+    /// Returns the number of deaths by `death`.
+    pub fn deaths(&self, death: Death) -> u32 {
+        self.deaths[death_index(death)]
+    }
+
+    /// Returns the average score on the level with the given `id`, if played.
+    pub fn average_score(&self, level: u8) -> Option<f64> {
+        match self.levels.get(usize::from(level)) {
+            Some((games, score)) if *games > 0 => Some(*score as f64 / f64::from(*games)),
+            _ => None,
+        }
+    }
+
+    /// Returns the `Stats` as labelled rows, as shown on the stats screen.
+    pub fn rows(&self) -> Vec<(String, String)> {
+        let mut rows = vec![
+            ("Games played".to_string(), self.games.to_string()),
+            ("Food eaten".to_string(), self.eaten.to_string()),
+            ("Longest snake".to_string(), self.longest.to_string()),
+            (
+                "Time played".to_string(),
+                format!("{}s", self.time.as_secs()),
+            ),
+        ];
+
+        for death in Death::ALL.iter() {
+            rows.push((
+                format!("Deaths: {}", death.name()),
+                self.deaths(*death).to_string(),
+            ));
+        }
+
+        for (id, level) in LEVELS.iter().enumerate() {
+            let average = self
+                .average_score(id as u8)
+                .map_or_else(|| "-".to_string(), |average| format!("{:.1}", average));
+
+            rows.push((format!("Average score: {}", level.name), average));
+        }
+
+        rows
+    }
+
+    /// Returns the `Stats` as CSV, one `stat,value` row each.
+    pub fn csv(&self) -> String {
+        let mut csv = String::from("stat,value\n");
+
+        for (label, value) in self.rows() {
+            csv.push_str(&format!("{},{}\n", csv_field(&label), csv_field(&value)));
+        }
+
+        csv
+    }
+
+    /// Exports the `Stats` as CSV to the file at `path`.
+    pub fn export(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.csv())
+    }
+
+    fn parse(text: &str) -> Self {
+        let mut stats = Self::default();
+
+        for line in text.lines() {
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let number = |i: usize| words.get(i).and_then(|word| word.parse::<u64>().ok());
+
+            match (words.first(), number(1), number(2)) {
+                (Some(&"games"), Some(games), _) => stats.games = games as u32,
+                (Some(&"eaten"), Some(eaten), _) => stats.eaten = eaten,
+                (Some(&"longest"), Some(longest), _) => stats.longest = longest as u32,
+                (Some(&"time"), Some(millis), _) => stats.time = Duration::from_millis(millis),
+                (Some(&"death"), Some(index), _) =>
+                    if let (Some(count), Some(deaths)) =
+                        (number(2), stats.deaths.get_mut(index as usize))
+                    {
+                        *deaths = count as u32;
+                    },
+                (Some(&"level"), Some(index), Some(games)) =>
+                    if let (Some(score), Some(level)) =
+                        (number(3), stats.levels.get_mut(index as usize))
+                    {
+                        *level = (games as u32, score);
+                    },
+                _ => {}
+            }
+        }
+
+        stats
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        writeln!(f, "games {}", self.games)?;
+        writeln!(f, "eaten {}", self.eaten)?;
+        writeln!(f, "longest {}", self.longest)?;
+        writeln!(f, "time {}", self.time.as_millis())?;
+        for (i, deaths) in self.deaths.iter().enumerate() {
+            writeln!(f, "death {} {}", i, deaths)?;
+        }
+        for (i, (games, score)) in self.levels.iter().enumerate() {
+            writeln!(f, "level {} {} {}", i, games, score)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn death_index(death: Death) -> usize {
+    Death::ALL
+        .iter()
+        .position(|d| *d == death)
+        .expect("All deaths are listed")
+}
+
+/// Quotes `field` if needed.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Config, Level};
+    use pretty_assertions::assert_eq;
+
+    fn stats() -> Stats {
+        let mut stats = Stats {
+            games: 3,
+            eaten: 12,
+            longest: 9,
+            time: Duration::from_millis(61_500),
+            ..Stats::default()
+        };
+        stats.deaths[1] = 2;
+        stats.levels[2] = (3, 120);
+
+        stats
+    }
+
+    #[test]
+    fn parse() {
+        let stats = stats();
+
+        assert_eq!(Stats::parse(&stats.to_string()), stats);
+        assert_eq!(Stats::parse("games 2\nunknown 3\nlongest\n"), Stats {
+            games: 2,
+            ..Stats::default()
+        });
+    }
+
+    #[test]
+    fn record() {
+        let config = Config::new((20.0, 10.0), Duration::from_millis(100));
+        let mut world = World::new(config, 42, Level::get(1));
+        while !world.is_over() {
+            world.update();
+        }
+
+        let mut stats = Stats::default();
+        stats.record(1, &world);
+        stats.record(1, &world);
+
+        assert_eq!(stats.games, 2);
+        assert_eq!(stats.time, world.elapsed() * 2);
+        assert_eq!(stats.deaths(Death::Wall), 2);
+        assert_eq!(stats.average_score(1), Some(f64::from(world.score())));
+        assert_eq!(stats.average_score(0), None);
+    }
+
+    #[test]
+    fn csv() {
+        let csv = stats().csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+
+        assert_eq!(&lines[..5], &[
+            "stat,value",
+            "Games played,3",
+            "Food eaten,12",
+            "Longest snake,9",
+            "Time played,61s",
+        ]);
+        assert!(lines.contains(&"Deaths: Hit a wall,2"));
+        assert!(lines.contains(&"Average score: Lol,40.0"));
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+}
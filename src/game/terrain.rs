@@ -0,0 +1,315 @@
+use crate::{
+    color::{Rgb, PALETTE},
+    physics::{Direction, Point},
+    theme::{Draw, Theme},
+    width,
+};
+use crossterm::style::{ResetColor, SetForegroundColor};
+use std::fmt::{Error, Formatter};
+
+/// A `Tile` of the `Terrain`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Tile {
+    Floor,
+    /// A wall, as built around arenas.
+    Wall,
+    /// An obstacle in the middle of the arena.
+    Rock,
+    /// One end of a pair of portals.
+    Portal,
+}
+
+/// A `Portal` teleports the head of a snake entering its cell to its `link`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Portal {
+    /// The cell of the portal.
+    pub cell: Point,
+    /// The cell of the linked portal, where the head leaves from.
+    pub link: Point,
+    /// The `Direction` the snake leaves to, or `None` to keep its own.
+    pub exit: Option<Direction>,
+}
+
+/// An `Obstacle` a `Level` places on the `Terrain`.
+///
+/// Positions are fractions of the bounds, as the size of the `World` depends
+/// on the terminal.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Obstacle {
+    /// Walls all around the arena.
+    Border,
+    /// A rectangle of rocks of `width` × `height` cells, from the cell at
+    /// (`x`, `y`) × bounds.
+    Rocks {
+        x:      f64,
+        y:      f64,
+        width:  u16,
+        height: u16,
+    },
+    /// A pair of linked portals, at `from` and `to` × bounds.
+    ///
+    /// Snakes entering `from` leave `to` to `exit`, and snakes entering `to`
+    /// leave `from` to the opposite of `exit`. They keep their `Direction`
+    /// when `exit` is `None`.
+    Portals {
+        from: (f64, f64),
+        to:   (f64, f64),
+        exit: Option<Direction>,
+    },
+}
+
+/// The `Terrain` of the `World`: a grid of `Tile`s, floor by default.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Terrain {
+    width:   usize,
+    height:  usize,
+    tiles:   Vec<Tile>,
+    portals: Vec<Portal>,
+}
+
+impl Tile {
+    /// Returns whether a snake hitting this `Tile` dies.
+    pub fn is_blocking(self) -> bool {
+        matches!(self, Self::Wall | Self::Rock)
+    }
+
+    /// Returns the glyph used to draw this `Tile` in `theme`.
+    pub fn glyph(self, theme: &Theme) -> &'static str {
+        match self {
+            Self::Floor => "  ",
+            Self::Wall => theme.wall,
+            Self::Rock => theme.rock,
+            Self::Portal => theme.portal,
+        }
+    }
+
+    /// Returns the color of this `Tile`.
+    pub fn color(self) -> Option<Rgb> {
+        match self {
+            Self::Floor => None,
+            Self::Wall => Some(PALETTE.wall),
+            Self::Rock => Some(PALETTE.rock),
+            Self::Portal => Some(PALETTE.portal),
+        }
+    }
+}
+
+impl Obstacle {
+    /// Places this `Obstacle` on `terrain`.
+    pub fn place(&self, terrain: &mut Terrain) {
+        let width = terrain.width as f64;
+        let height = terrain.height as f64;
+
+        match *self {
+            Self::Border =>
+                for (cell, _) in terrain.cells().collect::<Vec<_>>() {
+                    if cell.x == 0.0
+                        || cell.y == 0.0
+                        || cell.x == width - 1.0
+                        || cell.y == height - 1.0
+                    {
+                        terrain.set(cell, Tile::Wall);
+                    }
+                },
+            Self::Rocks {
+                x,
+                y,
+                width: w,
+                height: h,
+            } => {
+                let origin = Point::new((x * width).floor(), (y * height).floor());
+
+                for dy in 0..h {
+                    for dx in 0..w {
+                        terrain.set(
+                            origin + Point::new(f64::from(dx), f64::from(dy)),
+                            Tile::Rock,
+                        );
+                    }
+                }
+            }
+            Self::Portals { from, to, exit } => {
+                let from = Point::new((from.0 * width).floor(), (from.1 * height).floor());
+                let to = Point::new((to.0 * width).floor(), (to.1 * height).floor());
+
+                terrain.set(from, Tile::Portal);
+                terrain.set(to, Tile::Portal);
+                terrain.portals.push(Portal {
+                    cell: from,
+                    link: to,
+                    exit,
+                });
+                terrain.portals.push(Portal {
+                    cell: to,
+                    link: from,
+                    exit: exit.map(|exit| -exit),
+                });
+            }
+        }
+    }
+}
+
+impl Terrain {
+    /// Creates a new `Terrain` of `bounds` cells, without obstacles.
+    pub fn new(bounds: Point) -> Self {
+        let width = bounds.x as usize;
+        let height = bounds.y as usize;
+
+        Self {
+            width,
+            height,
+            tiles: vec![Tile::Floor; width * height],
+            portals: vec![],
+        }
+    }
+
+    /// Creates a new `Terrain` of `bounds` cells with `obstacles`.
+    pub fn with_obstacles(bounds: Point, obstacles: &[Obstacle]) -> Self {
+        let mut terrain = Self::new(bounds);
+        for obstacle in obstacles {
+            obstacle.place(&mut terrain);
+        }
+
+        terrain
+    }
+
+    /// Returns the `Tile` at `cell`, floor outside of the `Terrain`.
+    pub fn get(&self, cell: Point) -> Tile {
+        self.index(cell)
+            .map(|index| self.tiles[index])
+            .unwrap_or(Tile::Floor)
+    }
+
+    /// Puts `tile` at `cell`, if inside the `Terrain`.
+    pub fn set(&mut self, cell: Point, tile: Tile) {
+        if let Some(index) = self.index(cell) {
+            self.tiles[index] = tile;
+        }
+    }
+
+    /// Returns whether a snake hitting `cell` dies.
+    pub fn is_blocking(&self, cell: Point) -> bool {
+        self.get(cell).is_blocking()
+    }
+
+    /// Returns the `Portal` at `cell`, if any.
+    pub fn portal(&self, cell: Point) -> Option<Portal> {
+        self.portals
+            .iter()
+            .find(|portal| portal.cell == cell)
+            .copied()
+    }
+
+    /// Returns all the `Portal`s, two per pair.
+    pub fn portals(&self) -> &[Portal] {
+        &self.portals
+    }
+
+    /// Returns the number of rocks.
+    pub fn rocks(&self) -> usize {
+        self.tiles
+            .iter()
+            .filter(|tile| **tile == Tile::Rock)
+            .count()
+    }
+
+    /// Returns all the cells with their `Tile`.
+    pub fn cells(&self) -> impl Iterator<Item = (Point, Tile)> + '_ {
+        self.tiles.iter().enumerate().map(move |(index, tile)| {
+            let cell = Point::new((index % self.width) as f64, (index / self.width) as f64);
+
+            (cell, *tile)
+        })
+    }
+
+    /// Returns the cells that are not floor, with their `Tile`.
+    pub fn obstacles(&self) -> impl Iterator<Item = (Point, Tile)> + '_ {
+        self.cells().filter(|(_, tile)| *tile != Tile::Floor)
+    }
+
+    fn index(&self, cell: Point) -> Option<usize> {
+        let in_bounds = cell.x >= 0.0
+            && cell.y >= 0.0
+            && (cell.x as usize) < self.width
+            && (cell.y as usize) < self.height;
+
+        if in_bounds {
+            Some(cell.y as usize * self.width + cell.x as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl Draw for Terrain {
+    fn draw(&self, f: &mut Formatter, theme: &Theme) -> Result<(), Error> {
+        for (cell, tile) in self.obstacles() {
+            if let Some(color) = tile.color() {
+                write!(f, "{}", SetForegroundColor(color.color()))?;
+            }
+            write!(f, "{}{}", width::move_to(cell), tile.glyph(theme))?;
+        }
+
+        write!(f, "{}", ResetColor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn border() {
+        let terrain = Terrain::with_obstacles(Point::new(4.0, 3.0), &[Obstacle::Border]);
+
+        assert_eq!(terrain.obstacles().count(), 10);
+        assert_eq!(terrain.get(Point::new(0.0, 1.0)), Tile::Wall);
+        assert_eq!(terrain.get(Point::new(1.0, 1.0)), Tile::Floor);
+        assert!(terrain.is_blocking(Point::new(3.0, 2.0)));
+    }
+
+    #[test]
+    fn rocks() {
+        let terrain = Terrain::with_obstacles(Point::new(10.0, 10.0), &[Obstacle::Rocks {
+            x:      0.5,
+            y:      0.2,
+            width:  1,
+            height: 3,
+        }]);
+
+        assert_eq!(terrain.obstacles().collect::<Vec<_>>(), vec![
+            (Point::new(5.0, 2.0), Tile::Rock),
+            (Point::new(5.0, 3.0), Tile::Rock),
+            (Point::new(5.0, 4.0), Tile::Rock),
+        ]);
+        assert_eq!(terrain.get(Point::new(-1.0, 20.0)), Tile::Floor);
+    }
+
+    #[test]
+    fn portals() {
+        let terrain = Terrain::with_obstacles(Point::new(10.0, 10.0), &[Obstacle::Portals {
+            from: (0.1, 0.1),
+            to:   (0.8, 0.5),
+            exit: Some(Direction::Left),
+        }]);
+
+        assert_eq!(terrain.get(Point::new(1.0, 1.0)), Tile::Portal);
+        assert!(!terrain.is_blocking(Point::new(1.0, 1.0)));
+        assert_eq!(
+            terrain.portal(Point::new(1.0, 1.0)),
+            Some(Portal {
+                cell: Point::new(1.0, 1.0),
+                link: Point::new(8.0, 5.0),
+                exit: Some(Direction::Left),
+            })
+        );
+        assert_eq!(
+            terrain
+                .portal(Point::new(8.0, 5.0))
+                .and_then(|portal| portal.exit),
+            Some(Direction::Right)
+        );
+        assert_eq!(terrain.portal(Point::new(2.0, 1.0)), None);
+    }
+}
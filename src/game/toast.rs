@@ -0,0 +1,68 @@
+use crossterm::{
+    cursor::MoveTo,
+    style::{Attribute, SetAttribute},
+};
+use std::fmt::{Display, Error, Formatter};
+
+/// A `Toast` briefly shows a message on the first row of the arena.
+#[derive(Clone, Debug, Default)]
+pub struct Toast {
+    message: Option<String>,
+    frames:  u64,
+}
+
+impl Toast {
+    /// Shows `message` for `frames` frames, replacing the current one.
+    pub fn show(&mut self, message: impl Into<String>, frames: u64) {
+        self.message = Some(message.into());
+        self.frames = frames;
+    }
+
+    /// Counts one frame down, hiding the message when its time is up.
+    pub fn tick(&mut self) {
+        self.frames = self.frames.saturating_sub(1);
+        if self.frames == 0 {
+            self.message = None;
+        }
+    }
+
+    /// Returns the message being shown, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl Display for Toast {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        if let Some(message) = &self.message {
+            write!(
+                f,
+                "{}{} {} {}",
+                MoveTo(0, 0),
+                SetAttribute(Attribute::Reverse),
+                message,
+                SetAttribute(Attribute::Reset)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn expires() {
+        let mut toast = Toast::default();
+        toast.show("Hello", 2);
+        assert_eq!(toast.message(), Some("Hello"));
+
+        toast.tick();
+        assert_eq!(toast.message(), Some("Hello"));
+        toast.tick();
+        assert_eq!(toast.message(), None);
+    }
+}
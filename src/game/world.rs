@@ -1,79 +1,1064 @@
-use super::{Food, Snake};
+use super::{
+    Config,
+    Death,
+    Food,
+    GameMode,
+    Level,
+    Skin,
+    Snake,
+    SnakeSnapshot,
+    Snapshot,
+    SpeedCurve,
+    Terrain,
+    Tile,
+    SURVIVAL_ROCK_EVERY,
+    TIME_ATTACK,
+};
 use crate::{
-    events::KeyCode,
+    ai::{Bot, Difficulty},
+    consts::FOOD_SCORE,
     physics::{Direction, Moving, PathFragment, Point, Speed2D},
+    theme::{Draw, Theme},
 };
 use crossterm::terminal::{Clear, ClearType};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::{
-    fmt::{Display, Error, Formatter},
+    fmt::{Error, Formatter},
+    io::{self, Write},
     iter::Cycle,
     time::Duration,
     vec::IntoIter,
 };
 
+/// The index of the player's snake in `World::snakes`.
+pub const PLAYER: usize = 0;
+
+/// The side of the square the food walks along.
+const FOOD_PATH_SIZE: f64 = 3.0;
+
+/// How close to the head of a snake rocks may appear, in moves.
+const ROCK_MIN_DISTANCE: f64 = 4.0;
+
 #[derive(Debug)]
 pub struct World {
-    bounds: Point,
-    delta:  Duration,
-    snake:  Snake,
-    food:   Food<Cycle<IntoIter<PathFragment>>>,
+    bounds:  Point,
+    delta:   Duration,
+    rng:     StdRng,
+    terrain: Terrain,
+    speed:   SpeedCurve,
+    snakes:  Vec<Snake>,
+    bots:    Vec<Bot>,
+    food:    Food<Cycle<IntoIter<PathFragment>>>,
+    players: usize,
+    mode:    GameMode,
+    goal:    Option<u32>,
+    tick:    u32,
 }
 
 impl World {
-    pub fn new(bounds: Point, delta: Duration) -> Self {
-        let snake = Snake::new(
-            (0.0, 0.0),
-            Direction::Right,
-            Speed2D::from_per_sec((10.0, 10.0)),
-        );
+    /// Creates a new `World` for `level`, where randomness derives from
+    /// `seed`.
+    ///
+    /// The same `config`, `seed` and `level` always give the same `World`.
+    pub fn new(config: Config, seed: u64, level: &Level) -> Self {
+        Self::with_players(config, seed, level, 1)
+    }
 
-        let food = Food::new(
-            (2.0, 2.0),
-            vec![
-                PathFragment::from((Duration::from_secs(3), (3.0, 0.0))),
-                PathFragment::from((Duration::from_secs(3), (0.0, 3.0))),
-                PathFragment::from((Duration::from_secs(3), (-3.0, 0.0))),
-                PathFragment::from((Duration::from_secs(3), (0.0, -3.0))),
-            ]
-            .into_iter()
-            .cycle(),
-        );
+    /// Creates a new `World` for `level` with `players` snakes for humans,
+    /// first in `World::snakes`.
+    ///
+    /// Without `players`, as on a `Server` where humans join with
+    /// `add_player`, there is no local player: the `World` is never over and
+    /// has no effects, and `player`, `score`, `steer` and `autoplay` panic.
+    pub fn with_players(config: Config, seed: u64, level: &Level, players: usize) -> Self {
+        let Config { bounds, delta } = config;
+        let snakes = (0..players)
+            .map(|i| {
+                Snake::new(
+                    (
+                        (bounds.x / 4.0).floor(),
+                        (bounds.y * (i + 1) as f64 / (players + 1) as f64).floor(),
+                    ),
+                    Direction::Right,
+                    Speed2D::from_per_sec((level.speed.base, level.speed.base)),
+                )
+            })
+            .collect();
 
-        Self {
+        let mut world = Self {
             bounds,
             delta,
-            snake,
-            food,
+            rng: StdRng::seed_from_u64(seed),
+            terrain: Terrain::with_obstacles(bounds, level.obstacles),
+            speed: level.speed,
+            snakes,
+            bots: vec![],
+            food: Food::new((0.0, 0.0), food_path()),
+            players,
+            mode: GameMode::Classic,
+            goal: level.goal,
+            tick: 0,
+        };
+
+        let count = level.opponents.len();
+        for (i, difficulty) in level.opponents.iter().enumerate() {
+            let position = (
+                (bounds.x * 3.0 / 4.0).floor(),
+                (bounds.y * (i + 1) as f64 / (count + 1) as f64).floor(),
+            );
+            let snake = Snake::new(
+                position,
+                Direction::Left,
+                Speed2D::from_per_sec((level.speed.base, level.speed.base)),
+            )
+            .with_skin(Skin::Opponent);
+            let index = world.add_snake(snake);
+
+            world.bots.push(Bot::new(index, difficulty.strategy()));
+        }
+
+        world.spawn_food();
+        world
+    }
+
+    /// Sets the `GameMode`.
+    pub fn with_mode(mut self, mode: GameMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the `GameMode`.
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    /// Hands the player's snake over to a `Bot` of the given `Difficulty`.
+    pub fn autoplay(&mut self, difficulty: Difficulty) {
+        let player = self.local_player();
+        self.bots.push(Bot::new(player, difficulty.strategy()));
+    }
+
+    /// Returns the size of the `World`, in cells.
+    pub fn bounds(&self) -> Point {
+        self.bounds
+    }
+
+    /// Returns the `Terrain`, walls and obstacles.
+    pub fn terrain(&self) -> &Terrain {
+        &self.terrain
+    }
+
+    /// Returns the snakes, dead or alive, the player's first.
+    pub fn snakes(&self) -> &[Snake] {
+        &self.snakes
+    }
+
+    /// Returns the player's snake.
+    pub fn player(&self) -> &Snake {
+        &self.snakes[self.local_player()]
+    }
+
+    /// Returns the cell of the food.
+    pub fn food(&self) -> Point {
+        self.food.position.round().wrap(self.bounds)
+    }
+
+    /// Returns the score of the player.
+    pub fn score(&self) -> u32 {
+        self.score_of(self.local_player())
+    }
+
+    /// Returns the score of the `snake`.
+    pub fn score_of(&self, snake: usize) -> u32 {
+        self.snakes[snake].eaten() * FOOD_SCORE
+    }
+
+    /// Returns the number of updates so far.
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+
+    /// Returns the game time so far.
+    pub fn elapsed(&self) -> Duration {
+        self.delta * self.tick
+    }
+
+    /// Returns the game time left, in time attack.
+    pub fn time_left(&self) -> Option<Duration> {
+        match self.mode {
+            GameMode::TimeAttack => Some(TIME_ATTACK.saturating_sub(self.elapsed())),
+            _ => None,
+        }
+    }
+
+    /// Returns the score that finishes the level, in classic mode.
+    pub fn goal(&self) -> Option<u32> {
+        match self.mode {
+            GameMode::Classic => self.goal,
+            _ => None,
+        }
+    }
+
+    /// Returns whether a player reached the `goal`, finishing the level.
+    pub fn is_finished(&self) -> bool {
+        self.goal()
+            .is_some_and(|goal| (0..self.players).any(|snake| self.score_of(snake) >= goal))
+    }
+
+    /// Returns the names of the effects active on the player.
+    pub fn effects(&self) -> Vec<&'static str> {
+        let mut effects = vec![];
+        if self.players == 0 {
+            return effects;
+        }
+
+        if !self.mode.snakes_collide() {
+            effects.push("Intangible");
+        }
+        if self.speed.per_food > 0.0 && self.player().speed() >= self.speed.max {
+            effects.push("Max speed");
+        }
+
+        effects
+    }
+
+    /// Returns whether the game is over, i.e. all the players died, the time
+    /// is up or the level is finished.
+    ///
+    /// Never over without players.
+    pub fn is_over(&self) -> bool {
+        let dead = self.snakes[..self.players]
+            .iter()
+            .all(|snake| !snake.is_alive());
+        let time_up = self.time_left() == Some(Duration::from_secs(0));
+
+        self.players > 0 && (dead || time_up || self.is_finished())
+    }
+
+    /// Returns the number of human players, first in `World::snakes`.
+    pub fn players(&self) -> usize {
+        self.players
+    }
+
+    /// Adds a `Snake` for a human, on a free cell, and returns its index.
+    ///
+    /// Returns `None` when no cell is free.
+    pub fn add_player(&mut self) -> Option<usize> {
+        let snake = self.spawn_player()?;
+
+        Some(self.add_snake(snake))
+    }
+
+    /// Replaces the `snake` of a human with a new one on a free cell, and
+    /// returns whether it did.
+    pub fn respawn(&mut self, snake: usize) -> bool {
+        match self.spawn_player() {
+            Some(new) => {
+                self.snakes[snake] = new;
+                true
+            }
+            None => false,
         }
     }
 
-    pub fn handle(&mut self, code: KeyCode) {
-        match code {
-            KeyCode::Up => self.set_direction(Direction::Up),
-            KeyCode::Down => self.set_direction(Direction::Down),
-            KeyCode::Left => self.set_direction(Direction::Left),
-            KeyCode::Right => self.set_direction(Direction::Right),
-            _ => {}
+    /// Returns a new `Snake` for a human, on a free cell if any.
+    fn spawn_player(&mut self) -> Option<Snake> {
+        let position = self.free_cell(self.bounds, 0.0)?;
+
+        Some(Snake::new(
+            position,
+            Direction::Right,
+            Speed2D::from_per_sec((self.speed.base, self.speed.base)),
+        ))
+    }
+
+    /// Kills the `snake`.
+    pub fn kill(&mut self, snake: usize) {
+        self.snakes[snake].kill(Death::Left);
+    }
+
+    /// Adds a `Snake` and returns its index.
+    pub fn add_snake(&mut self, snake: Snake) -> usize {
+        self.snakes.push(snake);
+        self.snakes.len() - 1
+    }
+
+    /// Puts the food at `position`.
+    pub fn place_food(&mut self, position: Point) {
+        self.food = Food::new(position, food_path());
+    }
+
+    /// Steers the player to `direction`, from the next update.
+    pub fn steer(&mut self, direction: Direction) {
+        self.steer_snake(self.local_player(), direction);
+    }
+
+    /// Returns the index of the local player's snake.
+    ///
+    /// Panics without local player.
+    fn local_player(&self) -> usize {
+        assert!(self.players > 0, "No local player");
+        PLAYER
+    }
+
+    /// Steers the `snake` to `direction`, from the next update.
+    pub fn steer_snake(&mut self, snake: usize, direction: Direction) {
+        self.snakes[snake].set_direction(direction);
+    }
+
+    /// Returns a `Snapshot` of the `World`.
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            tick:    self.tick,
+            food:    self.food(),
+            terrain: self.terrain.obstacles().collect(),
+            snakes:  self
+                .snakes
+                .iter()
+                .enumerate()
+                .map(|(i, snake)| SnakeSnapshot {
+                    skin:  snake.skin(),
+                    alive: snake.is_alive(),
+                    score: self.score_of(i),
+                    body:  snake.body().cloned().collect(),
+                })
+                .collect(),
         }
     }
 
     pub fn update(&mut self) {
-        self.snake.commit_direction();
-        self.snake.r#move(self.delta);
+        self.drive_bots();
+
+        let delta = self.delta;
+        let mut steps = self
+            .snakes
+            .iter_mut()
+            .map(|snake| {
+                if snake.is_alive() {
+                    snake.commit_direction();
+                    snake.advance(delta)
+                } else {
+                    0
+                }
+            })
+            .collect::<Vec<_>>();
         self.food.r#move(self.delta);
+
+        // Fast snakes step several cells per update: they all step one cell
+        // at a time, so that none skips food, obstacles or other snakes
+        loop {
+            let moving = (0..self.snakes.len())
+                .filter(|i| steps[*i] > 0 && self.snakes[*i].is_alive())
+                .collect::<Vec<_>>();
+            if moving.is_empty() {
+                break;
+            }
+
+            for i in moving {
+                steps[i] -= 1;
+
+                match self.crosses(i).filter(|_| self.mode.snakes_collide()) {
+                    Some(death) => self.snakes[i].kill(death),
+                    None => self.step(i),
+                }
+            }
+
+            self.eat();
+            self.collide();
+        }
+
+        self.tick += 1;
+
+        if self.mode == GameMode::Survival {
+            let every = (SURVIVAL_ROCK_EVERY.as_secs_f64() / self.delta.as_secs_f64()).max(1.0);
+            if self.tick.is_multiple_of(every as u32) {
+                self.spawn_rock();
+            }
+        }
+    }
+
+    /// Puts a rock on a free cell, away from the heads of the snakes and off
+    /// the path of the food.
+    fn spawn_rock(&mut self) {
+        for _ in 0..100 {
+            let cell = match self.free_cell(self.bounds, 0.0) {
+                Some(cell) => cell,
+                None => return,
+            };
+            let near_head = self.snakes.iter().any(|snake| {
+                let distance = cell - snake.head();
+
+                snake.is_alive() && distance.x.abs() + distance.y.abs() < ROCK_MIN_DISTANCE
+            });
+
+            if !near_head && !self.is_on_food_path(cell) {
+                self.terrain.set(cell, Tile::Rock);
+                return;
+            }
+        }
     }
 
-    fn set_direction(&mut self, direction: Direction) {
-        self.snake.set_direction(direction);
+    /// Returns whether `cell` is in the square the food walks around.
+    fn is_on_food_path(&self, cell: Point) -> bool {
+        let offset = cell - self.food.origin();
+
+        (0.0..=FOOD_PATH_SIZE).contains(&offset.x) && (0.0..=FOOD_PATH_SIZE).contains(&offset.y)
     }
 
-    pub fn render(&self) {
-        println!("{}", self);
+    /// Returns whether the next diagonal step of the `snake` crosses a snake,
+    /// i.e. goes between two consecutive cells of a body.
+    fn crosses(&self, snake: usize) -> Option<Death> {
+        let direction = self.snakes[snake].direction();
+        if !direction.is_diagonal() {
+            return None;
+        }
+
+        let head = self.snakes[snake].head();
+        let delta = Point::from(direction);
+        let a = Point::new(head.x + delta.x, head.y).wrap(self.bounds);
+        let b = Point::new(head.x, head.y + delta.y).wrap(self.bounds);
+
+        self.snakes
+            .iter()
+            .enumerate()
+            .filter(|(_, other)| other.is_alive())
+            .find(|(_, other)| {
+                let body = other.body().collect::<Vec<_>>();
+
+                body.windows(2).any(|pair| {
+                    (*pair[0] == a && *pair[1] == b) || (*pair[0] == b && *pair[1] == a)
+                })
+            })
+            .map(|(i, _)| {
+                if i == snake {
+                    Death::Itself
+                } else {
+                    Death::Snake
+                }
+            })
+    }
+
+    /// Steps the `snake` one cell forward, through portals.
+    fn step(&mut self, snake: usize) {
+        let snake = &mut self.snakes[snake];
+        snake.step();
+        snake.wrap(self.bounds);
+
+        if let Some(portal) = self.terrain.portal(snake.head()) {
+            snake.teleport(
+                portal.link,
+                portal.exit.unwrap_or_else(|| snake.direction()),
+            );
+        }
+    }
+
+    fn drive_bots(&mut self) {
+        let mut bots = std::mem::take(&mut self.bots);
+
+        for bot in bots.iter_mut() {
+            if self.snakes[bot.snake()].is_alive() {
+                let direction = bot.direction(self);
+                self.snakes[bot.snake()].set_direction(direction);
+            }
+        }
+
+        self.bots = bots;
+    }
+
+    fn eat(&mut self) {
+        let food = self.food();
+        let speed = self.speed;
+
+        if let Some(snake) = self
+            .snakes
+            .iter_mut()
+            .find(|snake| snake.is_alive() && snake.head() == food)
+        {
+            snake.eat();
+            snake.set_speed(speed.at(snake.eaten()));
+            self.spawn_food();
+        }
+    }
+
+    fn collide(&mut self) {
+        let terrain = &self.terrain;
+        let snakes = &self.snakes;
+        let solid = self.mode.snakes_collide();
+        let dead = snakes
+            .iter()
+            .enumerate()
+            .filter(|(_, snake)| snake.is_alive())
+            .filter_map(|(i, snake)| {
+                let head = snake.head();
+                let hits_snake = || {
+                    snakes
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, other)| i != *j && other.is_alive())
+                        .any(|(_, other)| other.contains(head))
+                };
+
+                let death = if solid && snake.bites_itself() {
+                    Death::Itself
+                } else if terrain.is_blocking(head) {
+                    if terrain.get(head) == Tile::Rock {
+                        Death::Rock
+                    } else {
+                        Death::Wall
+                    }
+                } else if solid && hits_snake() {
+                    Death::Snake
+                } else {
+                    return None;
+                };
+
+                Some((i, death))
+            })
+            .collect::<Vec<_>>();
+
+        for (i, death) in dead {
+            self.snakes[i].kill(death);
+        }
+    }
+
+    fn spawn_food(&mut self) {
+        let area = Point::new(
+            (self.bounds.x - FOOD_PATH_SIZE).max(1.0),
+            (self.bounds.y - FOOD_PATH_SIZE).max(1.0),
+        );
+        // The crab walks around a square, which must be clear of obstacles and
+        // portals
+        // Without room, the food stays where it is
+        if let Some(position) = self.free_cell(area, FOOD_PATH_SIZE) {
+            self.place_food(position);
+        }
+    }
+
+    /// Returns a random cell in `area` (from the origin) without snakes,
+    /// with a square of `size` cells below and right of it of floor only.
+    ///
+    /// Returns `None` when no cell is free.
+    fn free_cell(&mut self, area: Point, size: f64) -> Option<Point> {
+        let is_free = |world: &Self, position: Point| {
+            let size = size as u16;
+            let clear = (0..=size).all(|dy| {
+                (0..=size).all(|dx| {
+                    let cell = position + Point::new(f64::from(dx), f64::from(dy));
+
+                    world.terrain.get(cell) == Tile::Floor
+                })
+            });
+
+            clear
+                && !world
+                    .snakes
+                    .iter()
+                    .any(|snake| snake.is_alive() && snake.contains(position))
+        };
+
+        // Give up on finding a random free cell after a while, and take the
+        // first one
+        for _ in 0..100 {
+            let position = Point::new(
+                self.rng.gen_range(0, area.x as u16) as f64,
+                self.rng.gen_range(0, area.y as u16) as f64,
+            );
+
+            if is_free(self, position) {
+                return Some(position);
+            }
+        }
+
+        (0..area.y as u16)
+            .flat_map(|y| (0..area.x as u16).map(move |x| Point::new(f64::from(x), f64::from(y))))
+            .find(|position| is_free(self, *position))
+    }
+
+    /// Renders the `World` alone on `out`, drawn with `theme`.
+    pub fn render(&self, out: &mut (impl Write + ?Sized), theme: &Theme) -> io::Result<()> {
+        write!(out, "{}{}", Clear(ClearType::All), self.themed(theme))
     }
 }
 
-impl Display for World {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        write!(f, "{}{}{}", Clear(ClearType::All), self.snake, self.food)
+impl Draw for World {
+    fn draw(&self, f: &mut Formatter, theme: &Theme) -> Result<(), Error> {
+        draw(f, theme, &self.terrain, &self.snakes, &self.food)
+    }
+}
+
+/// Draws the `terrain`, then the living `snakes` and the `food` over it,
+/// with `theme`.
+pub(super) fn draw(
+    f: &mut Formatter,
+    theme: &Theme,
+    terrain: &Terrain,
+    snakes: &[Snake],
+    food: &impl Draw,
+) -> Result<(), Error> {
+    terrain.draw(f, theme)?;
+    for snake in snakes.iter().filter(|snake| snake.is_alive()) {
+        snake.draw(f, theme)?;
+    }
+
+    food.draw(f, theme)
+}
+
+/// The crab walks in squares.
+fn food_path() -> Cycle<IntoIter<PathFragment>> {
+    vec![
+        PathFragment::from((Duration::from_secs(3), (FOOD_PATH_SIZE, 0.0))),
+        PathFragment::from((Duration::from_secs(3), (0.0, FOOD_PATH_SIZE))),
+        PathFragment::from((Duration::from_secs(3), (-FOOD_PATH_SIZE, 0.0))),
+        PathFragment::from((Duration::from_secs(3), (0.0, -FOOD_PATH_SIZE))),
+    ]
+    .into_iter()
+    .cycle()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Obstacle;
+    use pretty_assertions::assert_eq;
+
+    const LEVEL: Level = Level {
+        name:      "Test",
+        opponents: &[],
+        obstacles: &[],
+        speed:     SpeedCurve::constant(10.0),
+        goal:      None,
+    };
+
+    fn world() -> World {
+        World::new(
+            Config::new((20.0, 10.0), Duration::from_millis(100)),
+            42,
+            &LEVEL,
+        )
+    }
+
+    #[test]
+    fn new() {
+        let level = Level {
+            name:      "Test",
+            opponents: &[Difficulty::Easy, Difficulty::Hard],
+            obstacles: &[],
+            speed:     SpeedCurve::constant(10.0),
+            goal:      None,
+        };
+        let world = World::new(
+            Config::new((20.0, 10.0), Duration::from_millis(100)),
+            42,
+            &level,
+        );
+
+        assert_eq!(world.snakes().len(), 3);
+        assert_eq!(world.player().head(), Point::new(5.0, 5.0));
+        assert_eq!(world.snakes()[1].head(), Point::new(15.0, 3.0));
+        assert_eq!(world.snakes()[2].head(), Point::new(15.0, 6.0));
+    }
+
+    #[test]
+    fn no_local_player() {
+        let level = Level {
+            opponents: &[Difficulty::Easy],
+            ..LEVEL
+        };
+        let config = Config::new((20.0, 10.0), Duration::from_millis(100));
+        let mut world = World::with_players(config, 42, &level, 0);
+
+        let snake = world.add_player().unwrap();
+        for _ in 0..50 {
+            world.update();
+        }
+        world.kill(snake);
+        world.update();
+
+        assert!(!world.is_over());
+        assert_eq!(world.effects(), Vec::<&str>::new());
+        assert_eq!(world.snapshot().snakes.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "No local player")]
+    fn no_player() {
+        let config = Config::new((20.0, 10.0), Duration::from_millis(100));
+        World::with_players(config, 42, &LEVEL, 0).player();
+    }
+
+    #[test]
+    fn eat() {
+        let mut world = world();
+        world.place_food(Point::new(6.0, 5.0));
+
+        world.update();
+        assert_eq!(world.player().eaten(), 1);
+        assert_eq!(world.player().speed(), 10.0);
+        assert_ne!(world.food(), Point::new(6.0, 5.0));
+    }
+
+    #[test]
+    fn speed_up() {
+        let level = Level {
+            speed: SpeedCurve {
+                base:     10.0,
+                per_food: 5.0,
+                max:      20.0,
+            },
+            ..LEVEL
+        };
+        let mut world = World::new(
+            Config::new((20.0, 10.0), Duration::from_millis(100)),
+            42,
+            &level,
+        );
+
+        world.place_food(Point::new(6.0, 5.0));
+        world.update();
+        assert_eq!(world.player().speed(), 15.0);
+
+        // 1.5 cells per update from (6, 5), eating on the way to (9, 5)
+        world.place_food(Point::new(8.0, 5.0));
+        world.update();
+        world.update();
+        assert_eq!(world.player().head(), Point::new(9.0, 5.0));
+        assert_eq!(world.player().eaten(), 2);
+        assert_eq!(world.player().speed(), 20.0);
+        assert_eq!(world.effects(), vec!["Max speed"]);
+    }
+
+    #[test]
+    fn no_skipped_cells() {
+        let level = Level {
+            obstacles: &[Obstacle::Rocks {
+                x:      0.3,
+                y:      0.0,
+                width:  1,
+                height: 10,
+            }],
+            ..LEVEL
+        };
+        // Two cells per update
+        let mut world = World::new(
+            Config::new((20.0, 10.0), Duration::from_millis(200)),
+            42,
+            &level,
+        );
+
+        world.update();
+        assert!(world.is_over());
+        assert_eq!(world.player().head(), Point::new(6.0, 5.0));
+    }
+
+    #[test]
+    fn diagonals() {
+        let mut world = world();
+        world.steer(Direction::DownRight);
+
+        // 10 cells per second, but only 1 / √2 diagonal cells per update
+        world.update();
+        assert_eq!(world.player().head(), Point::new(5.0, 5.0));
+        world.update();
+        assert_eq!(world.player().head(), Point::new(6.0, 6.0));
+
+        // Reversing is not allowed on diagonals either
+        world.steer(Direction::UpLeft);
+        world.update();
+        assert_eq!(world.player().direction(), Direction::DownRight);
+    }
+
+    #[test]
+    fn diagonal_crossing() {
+        let mut world = world();
+        // A still wall going from (8, 5) up-left to (6, 3)
+        let mut wall = Snake::new(
+            (8.0, 5.0),
+            Direction::UpLeft,
+            Speed2D::from_per_sec((1.0, 1.0)),
+        );
+        for _ in 0..2 {
+            wall.r#move(Duration::from_secs(2));
+            wall.eat();
+        }
+        wall.set_speed(0.0);
+        world.add_snake(wall);
+
+        // From (5, 5) to (6, 4), then between (6, 3) and (7, 4)
+        world.steer(Direction::UpRight);
+        for _ in 0..3 {
+            world.update();
+        }
+        assert_eq!(world.player().head(), Point::new(6.0, 4.0));
+        assert!(world.is_over());
+        assert_eq!(world.player().death(), Some(Death::Snake));
+    }
+
+    #[test]
+    fn collide() {
+        let mut world = world();
+        let mut snake = Snake::new(
+            (6.0, 5.0),
+            Direction::Down,
+            Speed2D::from_per_sec((1.0, 1.0)),
+        );
+        snake.r#move(Duration::from_secs(1));
+        snake.eat();
+        world.add_snake(snake);
+
+        world.update();
+        assert!(world.is_over());
+        assert_eq!(world.player().death(), Some(Death::Snake));
+        assert!(world.snakes()[1].is_alive());
+    }
+
+    #[test]
+    fn walls() {
+        let level = Level {
+            name:      "Test",
+            opponents: &[],
+            obstacles: &[Obstacle::Border, Obstacle::Rocks {
+                x:      0.5,
+                y:      0.0,
+                width:  1,
+                height: 10,
+            }],
+            speed:     SpeedCurve::constant(10.0),
+            goal:      None,
+        };
+        let mut world = World::new(
+            Config::new((20.0, 10.0), Duration::from_millis(100)),
+            42,
+            &level,
+        );
+
+        for _ in 0..100 {
+            let food = world.food();
+            assert!(!world.terrain().is_blocking(food));
+            world.spawn_food();
+        }
+
+        // The player starts at (5, 5) heading right, to the rocks at x = 10
+        for _ in 0..4 {
+            world.update();
+        }
+        assert!(!world.is_over());
+        world.update();
+        assert!(world.is_over());
+        assert_eq!(world.player().death(), Some(Death::Rock));
+    }
+
+    #[test]
+    fn portals() {
+        let level = Level {
+            name:      "Test",
+            opponents: &[],
+            obstacles: &[Obstacle::Portals {
+                from: (0.4, 0.5),
+                to:   (0.8, 0.2),
+                exit: Some(Direction::Down),
+            }],
+            speed:     SpeedCurve::constant(10.0),
+            goal:      None,
+        };
+        let mut world = World::new(
+            Config::new((20.0, 10.0), Duration::from_millis(100)),
+            42,
+            &level,
+        );
+        world.place_food(Point::new(0.0, 0.0));
+
+        // The player starts at (5, 5) heading right, to the portal at (8, 5)
+        world.update();
+        world.snakes[PLAYER].eat();
+        world.update();
+        world.update();
+        assert_eq!(world.player().direction(), Direction::Down);
+        assert_eq!(world.player().body().cloned().collect::<Vec<_>>(), vec![
+            Point::new(16.0, 2.0),
+            Point::new(7.0, 5.0)
+        ]);
+
+        world.update();
+        assert_eq!(world.player().body().cloned().collect::<Vec<_>>(), vec![
+            Point::new(16.0, 3.0),
+            Point::new(16.0, 2.0)
+        ]);
+        assert!(!world.is_over());
+    }
+
+    #[test]
+    fn two_players() {
+        let mut world = World::with_players(
+            Config::new((20.0, 10.0), Duration::from_millis(100)),
+            42,
+            &LEVEL,
+            2,
+        );
+        assert_eq!(world.players(), 2);
+
+        world.kill(PLAYER);
+        assert!(!world.is_over());
+        world.kill(1);
+        assert!(world.is_over());
+    }
+
+    #[test]
+    fn time_attack() {
+        let mut world = world().with_mode(GameMode::TimeAttack);
+        world.steer(Direction::Up);
+
+        // 10 updates per second
+        for _ in 0..599 {
+            world.update();
+            // Loop around
+            if world.tick().is_multiple_of(5) {
+                let direction = world.player().direction().turn_right();
+                world.steer(direction);
+            }
+        }
+        assert_eq!(world.time_left(), Some(Duration::from_millis(100)));
+        assert!(!world.is_over());
+
+        world.update();
+        assert!(world.is_over());
+        assert!(world.player().is_alive());
+    }
+
+    #[test]
+    fn finished() {
+        let level = Level {
+            goal: Some(20),
+            ..LEVEL
+        };
+        let config = Config::new((20.0, 10.0), Duration::from_millis(100));
+        let mut world = World::new(config, 42, &level);
+        world.autoplay(Difficulty::Hard);
+
+        while !world.is_over() {
+            world.update();
+        }
+        assert!(world.is_finished());
+        assert!(world.player().is_alive());
+        assert_eq!(world.score(), 20);
+
+        let world = World::new(config, 42, &level).with_mode(GameMode::Zen);
+        assert_eq!(world.goal(), None);
+    }
+
+    #[test]
+    fn rocks_avoid_food() {
+        let mut world = world();
+        world.place_food(Point::new(10.0, 2.0));
+
+        // Only the square of the food is free
+        for y in 0..10 {
+            for x in 0..20 {
+                if !(10..=13).contains(&x) || !(2..=5).contains(&y) {
+                    world
+                        .terrain
+                        .set(Point::new(f64::from(x), f64::from(y)), Tile::Rock);
+                }
+            }
+        }
+        let rocks = world.terrain.obstacles().count();
+        world.spawn_rock();
+        assert_eq!(world.terrain.obstacles().count(), rocks);
+
+        // No free cell at all
+        for y in 2..=5 {
+            for x in 10..=13 {
+                world
+                    .terrain
+                    .set(Point::new(f64::from(x), f64::from(y)), Tile::Rock);
+            }
+        }
+        assert_eq!(world.free_cell(world.bounds, 0.0), None);
+        assert_eq!(world.add_player(), None);
+        world.spawn_food();
+        assert_eq!(world.food.origin(), Point::new(10.0, 2.0));
+    }
+
+    #[test]
+    fn survival() {
+        let mut world = world().with_mode(GameMode::Survival);
+        world.autoplay(Difficulty::Hard);
+
+        for _ in 0..90 {
+            world.update();
+        }
+        assert_eq!(world.terrain().rocks(), 3);
+        assert!(!world.is_over());
+    }
+
+    #[test]
+    fn zen() {
+        let mut world = world().with_mode(GameMode::Zen);
+        let mut snake = Snake::new(
+            (6.0, 5.0),
+            Direction::Down,
+            Speed2D::from_per_sec((1.0, 1.0)),
+        );
+        snake.r#move(Duration::from_secs(1));
+        snake.eat();
+        world.add_snake(snake);
+
+        world.update();
+        assert!(!world.is_over());
+        assert!(world.player().is_alive());
+    }
+
+    #[test]
+    fn zen_walls() {
+        let level = Level {
+            name:      "Test",
+            opponents: &[],
+            obstacles: &[Obstacle::Border, Obstacle::Rocks {
+                x:      0.5,
+                y:      0.0,
+                width:  1,
+                height: 10,
+            }],
+            speed:     SpeedCurve::constant(10.0),
+            goal:      None,
+        };
+        let mut world = World::new(
+            Config::new((20.0, 10.0), Duration::from_millis(100)),
+            42,
+            &level,
+        )
+        .with_mode(GameMode::Zen);
+
+        // The player starts at (5, 5) heading right, to the rocks at x = 10
+        for _ in 0..5 {
+            world.update();
+        }
+        assert!(world.is_over());
+        assert_eq!(world.player().death(), Some(Death::Rock));
+    }
+
+    #[test]
+    fn autoplay() {
+        let mut world = world();
+        world.autoplay(Difficulty::Hard);
+
+        for _ in 0..100 {
+            world.update();
+        }
+
+        assert!(!world.is_over());
+        assert!(world.player().eaten() > 0);
+    }
+
+    #[test]
+    fn same_seed_same_world() {
+        let mut world1 = world();
+        let mut world2 = world();
+
+        for _ in 0..50 {
+            world1.update();
+            world2.update();
+        }
+
+        assert_eq!(world1.food(), world2.food());
     }
 }
@@ -5,12 +5,54 @@
 // '🍭', '🐍', '👅', '🦀', '😀', '😜', '💖', '💣', '💤', '💭', '🤙', '🧑', '🦹',
 // '🧜', '🦊', '🥝', '🦖', '🦚', '🦁', '🔥', '💎', '💸', '🧲', '💊',
 
+pub mod ai;
+pub mod backend;
+pub mod cli;
+pub mod color;
 pub mod consts;
 pub mod events;
 pub mod game;
+pub mod net;
 pub mod physics;
 pub mod screen;
+pub mod storage;
+#[cfg(test)]
+mod testing;
+pub mod theme;
+pub mod width;
+
+use cli::{Options, USAGE};
+use consts::{FPS, SERVER_HEIGHT, SERVER_WIDTH};
+use game::{Config, Level};
+use std::{env, process, time::Duration};
 
 fn main() {
-    game::Game::new().init();
+    match Options::parse(env::args().skip(1)) {
+        Ok(Some(Options {
+            server: Some(addr),
+            level,
+            seed,
+            ..
+        })) => {
+            let level = Level::get(level);
+            let config = Config::new(
+                (f64::from(SERVER_WIDTH), f64::from(SERVER_HEIGHT)),
+                Duration::from_millis(1000 / FPS),
+            );
+
+            match net::Server::bind(&addr, config, seed.unwrap_or_else(rand::random), level) {
+                Ok(mut server) => server.run(),
+                Err(err) => {
+                    eprintln!("Cannot listen on {}: {}", addr, err);
+                    process::exit(1);
+                }
+            }
+        }
+        Ok(Some(options)) => game::Game::new(options).init(),
+        Ok(None) => println!("{}", USAGE),
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    }
 }
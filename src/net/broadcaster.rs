@@ -0,0 +1,225 @@
+use super::{outbox::Outbox, Endpoint, Listener, ServerMessage};
+use crate::{
+    game::{Snapshot, PLAYER},
+    physics::Point,
+};
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+        Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+/// How long a write to a viewer may block before it is dropped.
+const WRITE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// A `Broadcaster` publishes the snapshots of a local game to read-only
+/// viewers.
+///
+/// Viewers speak the multiplayer protocol: they are welcomed as the player,
+/// then receive every snapshot. Each one is written to from its own thread,
+/// so that the game never waits for them.
+#[derive(Debug)]
+pub struct Broadcaster {
+    endpoint: Endpoint,
+    viewers:  Arc<Mutex<Vec<Outbox>>>,
+    /// Whether the `Broadcaster` is gone, for the thread welcoming viewers
+    /// to stop.
+    closed:   Arc<AtomicBool>,
+    accepter: Option<JoinHandle<()>>,
+}
+
+impl Broadcaster {
+    /// Creates a new `Broadcaster` listening on `endpoint`, for a `World` of
+    /// `bounds` cells.
+    pub fn bind(endpoint: &Endpoint, bounds: Point) -> io::Result<Self> {
+        let listener = endpoint.bind()?;
+        let endpoint = listener.endpoint()?;
+        let viewers = Arc::new(Mutex::new(vec![]));
+        let closed = Arc::new(AtomicBool::new(false));
+
+        let accepted = Arc::clone(&viewers);
+        let stop = Arc::clone(&closed);
+        let accepter = thread::spawn(move || accept(listener, bounds, accepted, &stop));
+
+        Ok(Self {
+            endpoint,
+            viewers,
+            closed,
+            accepter: Some(accepter),
+        })
+    }
+
+    /// Returns the `Endpoint` the `Broadcaster` listens on.
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+
+    /// Returns the number of connected viewers.
+    pub fn viewers(&self) -> usize {
+        self.viewers.lock().expect("Viewers are poisoned").len()
+    }
+
+    /// Queues `snapshot` for the viewers, dropping the ones that left or
+    /// are too slow.
+    pub fn publish(&self, snapshot: &Snapshot) {
+        let line = ServerMessage::Snapshot(snapshot.clone()).encode();
+
+        self.viewers
+            .lock()
+            .expect("Viewers are poisoned")
+            .retain(|viewer| {
+                let sent = viewer.send(line.clone()).is_ok();
+                if !sent {
+                    viewer.close();
+                }
+                sent
+            });
+    }
+}
+
+impl Drop for Broadcaster {
+    /// Stops listening, waking the thread welcoming viewers with a last
+    /// connection, and removes the Unix socket.
+    fn drop(&mut self) {
+        self.closed.store(true, Ordering::SeqCst);
+
+        if let Some(accepter) = self.accepter.take() {
+            if self.endpoint.connect().is_ok() {
+                let _ = accepter.join();
+            }
+        }
+
+        #[cfg(unix)]
+        if let Endpoint::Unix(path) = &self.endpoint {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// Welcomes the viewers, until the `Broadcaster` is `closed`.
+fn accept(
+    listener: Listener,
+    bounds: Point,
+    viewers: Arc<Mutex<Vec<Outbox>>>,
+    closed: &AtomicBool,
+) {
+    let welcome = ServerMessage::Welcome {
+        snake: PLAYER,
+        bounds,
+    }
+    .encode();
+
+    loop {
+        let viewer = listener.accept();
+        if closed.load(Ordering::SeqCst) {
+            return;
+        }
+        let outbox = viewer.and_then(|viewer| {
+            viewer.set_write_timeout(Some(WRITE_TIMEOUT))?;
+            Outbox::new(viewer)
+        });
+
+        if let Ok(outbox) = outbox {
+            if outbox.send(welcome.clone()).is_ok() {
+                viewers.lock().expect("Viewers are poisoned").push(outbox);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{game::Tile, net::Watcher};
+    use pretty_assertions::assert_eq;
+    use std::time::Instant;
+
+    #[test]
+    fn publish() {
+        let bounds = Point::new(20.0, 10.0);
+        let broadcaster = Broadcaster::bind(&Endpoint::parse("127.0.0.1:0"), bounds).unwrap();
+        let mut watcher = Watcher::connect(broadcaster.endpoint()).unwrap();
+        assert_eq!(watcher.bounds(), bounds);
+
+        while broadcaster.viewers() == 0 {
+            thread::yield_now();
+        }
+
+        let snapshot = Snapshot {
+            tick: 3,
+            ..Snapshot::default()
+        };
+        broadcaster.publish(&snapshot);
+
+        let mut received = None;
+        while received.is_none() {
+            received = watcher.poll().unwrap();
+        }
+        assert_eq!(received, Some(snapshot));
+
+        drop(watcher);
+        for _ in 0..100 {
+            broadcaster.publish(&Snapshot::default());
+            if broadcaster.viewers() == 0 {
+                return;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        panic!("The viewer is still there");
+    }
+
+    #[test]
+    fn stalled_viewer() {
+        let bounds = Point::new(20.0, 10.0);
+        let broadcaster = Broadcaster::bind(&Endpoint::parse("127.0.0.1:0"), bounds).unwrap();
+        // Never reads
+        let _viewer = broadcaster.endpoint().connect().unwrap();
+
+        while broadcaster.viewers() == 0 {
+            thread::yield_now();
+        }
+
+        let snapshot = Snapshot {
+            terrain: vec![(Point::new(1.0, 1.0), Tile::Wall); 2_000],
+            ..Snapshot::default()
+        };
+        for _ in 0..100_000 {
+            let start = Instant::now();
+            broadcaster.publish(&snapshot);
+            assert!(start.elapsed() < Duration::from_millis(10));
+
+            if broadcaster.viewers() == 0 {
+                return;
+            }
+        }
+        panic!("The viewer is still there");
+    }
+
+    #[test]
+    fn close() {
+        let bounds = Point::new(20.0, 10.0);
+        let broadcaster = Broadcaster::bind(&Endpoint::parse("127.0.0.1:0"), bounds).unwrap();
+        let endpoint = broadcaster.endpoint().clone();
+
+        drop(broadcaster);
+        assert!(endpoint.connect().is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn close_unix() {
+        let path =
+            std::env::temp_dir().join(format!("snake-broadcaster-{}.sock", std::process::id()));
+        let broadcaster =
+            Broadcaster::bind(&Endpoint::Unix(path.clone()), Point::new(20.0, 10.0)).unwrap();
+        assert!(path.exists());
+
+        drop(broadcaster);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,128 @@
+use super::{ClientMessage, ServerMessage, VERSION};
+use crate::{
+    consts::{MAX_ARENA_HEIGHT, MAX_ARENA_WIDTH},
+    game::Snapshot,
+    physics::{Direction, Point},
+};
+use std::{
+    io::{self, BufRead, BufReader, Error, ErrorKind, Write},
+    net::{TcpStream, ToSocketAddrs},
+    sync::mpsc::{channel, Receiver, TryRecvError},
+    thread,
+};
+
+/// A `Client` plays on a `Server`.
+#[derive(Debug)]
+pub struct Client {
+    stream:   TcpStream,
+    snake:    usize,
+    bounds:   Point,
+    messages: Receiver<ServerMessage>,
+}
+
+impl Client {
+    /// Connects to the `Server` at `addr` and joins the game.
+    pub fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let mut stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+
+        writeln!(
+            stream,
+            "{}",
+            ClientMessage::Hello { version: VERSION }.encode()
+        )?;
+
+        let (snake, bounds) = read_welcome(&mut reader)?;
+        let messages = receive(reader);
+
+        Ok(Self {
+            stream,
+            snake,
+            bounds,
+            messages,
+        })
+    }
+
+    /// Returns the index of the played snake in the snapshots.
+    pub fn snake(&self) -> usize {
+        self.snake
+    }
+
+    /// Returns the size of the `World`, in cells.
+    pub fn bounds(&self) -> Point {
+        self.bounds
+    }
+
+    /// Steers to `direction`, having seen the snapshot of `tick`.
+    pub fn steer(&mut self, tick: u32, direction: Direction) -> io::Result<()> {
+        writeln!(
+            self.stream,
+            "{}",
+            ClientMessage::Steer { tick, direction }.encode()
+        )
+    }
+
+    /// Handles the received messages, returning the latest `Snapshot`, if
+    /// any. Snapshots with cells out of the bounds are dropped.
+    ///
+    /// Fails when the `Server` is gone.
+    pub fn poll(&mut self) -> io::Result<Option<Snapshot>> {
+        let mut snapshot = None;
+
+        loop {
+            match self.messages.try_recv() {
+                Ok(ServerMessage::Snapshot(latest)) if latest.fits(self.bounds) =>
+                    snapshot = Some(latest),
+                Ok(ServerMessage::Spawn { snake }) => self.snake = snake,
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => return Ok(snapshot),
+                Err(TryRecvError::Disconnected) =>
+                    return Err(Error::new(ErrorKind::ConnectionAborted, "Server is gone")),
+            }
+        }
+    }
+}
+
+/// Reads the `Welcome` of the server, returning the snake and the bounds.
+pub(super) fn read_welcome(reader: &mut impl BufRead) -> io::Result<(usize, Point)> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+
+    match ServerMessage::decode(&line) {
+        Ok(ServerMessage::Welcome { bounds, .. })
+            if bounds.x > f64::from(MAX_ARENA_WIDTH) || bounds.y > f64::from(MAX_ARENA_HEIGHT) =>
+            Err(Error::new(ErrorKind::InvalidData, "World too large")),
+        Ok(ServerMessage::Welcome { snake, bounds }) => Ok((snake, bounds)),
+        Ok(ServerMessage::Refused { reason }) =>
+            Err(Error::new(ErrorKind::ConnectionRefused, reason)),
+        _ => Err(Error::new(ErrorKind::InvalidData, "Unexpected message")),
+    }
+}
+
+/// Receives the messages read from `reader` in a thread.
+pub(super) fn receive(reader: impl BufRead + Send + 'static) -> Receiver<ServerMessage> {
+    let (sender, messages) = channel();
+
+    thread::spawn(move || {
+        for line in reader.lines() {
+            let message = match line.map(|line| ServerMessage::decode(&line)) {
+                Ok(Ok(message)) => message,
+                Ok(Err(_)) => continue,
+                Err(_) => break,
+            };
+
+            if sender.send(message).is_err() {
+                break;
+            }
+        }
+    });
+
+    messages
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        let _ = writeln!(self.stream, "{}", ClientMessage::Bye.encode());
+    }
+}
@@ -0,0 +1,240 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::{self, Read, Write},
+    net::{Shutdown, TcpListener, TcpStream},
+    time::Duration,
+};
+#[cfg(unix)]
+use std::{
+    fs,
+    io::ErrorKind,
+    os::unix::{
+        fs::FileTypeExt,
+        net::{UnixListener, UnixStream},
+    },
+    path::PathBuf,
+};
+
+/// An `Endpoint` is where to listen or connect: a TCP address, or a Unix
+/// socket when prefixed with `unix:`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Endpoint {
+    Tcp(String),
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+/// A `Listener` accepts connections on an `Endpoint`.
+#[derive(Debug)]
+pub enum Listener {
+    Tcp(TcpListener),
+    #[cfg(unix)]
+    Unix(UnixListener),
+}
+
+/// A `Stream` is a connection to an `Endpoint`.
+#[derive(Debug)]
+pub enum Stream {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+impl Endpoint {
+    /// Parses an `Endpoint` like `127.0.0.1:7777` or `unix:/tmp/snake.sock`.
+    pub fn parse(addr: &str) -> Self {
+        #[cfg(unix)]
+        if let Some(path) = addr.strip_prefix("unix:") {
+            return Self::Unix(path.into());
+        }
+
+        Self::Tcp(addr.to_string())
+    }
+
+    /// Listens on this `Endpoint`, replacing any stale Unix socket.
+    ///
+    /// Fails rather than replacing anything else than a socket nobody listens
+    /// on.
+    pub fn bind(&self) -> io::Result<Listener> {
+        match self {
+            Self::Tcp(addr) => TcpListener::bind(addr).map(Listener::Tcp),
+            #[cfg(unix)]
+            Self::Unix(path) => {
+                match fs::symlink_metadata(path) {
+                    Ok(metadata) if !metadata.file_type().is_socket() =>
+                        return Err(io::Error::new(
+                            ErrorKind::AlreadyExists,
+                            format!("{} exists and is not a socket", path.display()),
+                        )),
+                    Ok(_) if UnixStream::connect(path).is_ok() =>
+                        return Err(io::Error::new(
+                            ErrorKind::AddrInUse,
+                            format!("{} is in use", path.display()),
+                        )),
+                    Ok(_) => fs::remove_file(path)?,
+                    Err(err) if err.kind() == ErrorKind::NotFound => {}
+                    Err(err) => return Err(err),
+                }
+
+                UnixListener::bind(path).map(Listener::Unix)
+            }
+        }
+    }
+
+    /// Connects to this `Endpoint`.
+    pub fn connect(&self) -> io::Result<Stream> {
+        match self {
+            Self::Tcp(addr) => {
+                let stream = TcpStream::connect(addr)?;
+                stream.set_nodelay(true)?;
+
+                Ok(Stream::Tcp(stream))
+            }
+            #[cfg(unix)]
+            Self::Unix(path) => UnixStream::connect(path).map(Stream::Unix),
+        }
+    }
+}
+
+impl Display for Endpoint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Tcp(addr) => write!(f, "{}", addr),
+            #[cfg(unix)]
+            Self::Unix(path) => write!(f, "unix:{}", path.display()),
+        }
+    }
+}
+
+impl Listener {
+    /// Returns the `Endpoint` this `Listener` listens on.
+    pub fn endpoint(&self) -> io::Result<Endpoint> {
+        match self {
+            Self::Tcp(listener) => Ok(Endpoint::Tcp(listener.local_addr()?.to_string())),
+            #[cfg(unix)]
+            Self::Unix(listener) => listener
+                .local_addr()?
+                .as_pathname()
+                .map(|path| Endpoint::Unix(path.into()))
+                .ok_or_else(|| io::Error::other("Unnamed socket")),
+        }
+    }
+
+    /// Waits for the next connection.
+    pub fn accept(&self) -> io::Result<Stream> {
+        match self {
+            Self::Tcp(listener) => {
+                let (stream, _) = listener.accept()?;
+                stream.set_nodelay(true)?;
+
+                Ok(Stream::Tcp(stream))
+            }
+            #[cfg(unix)]
+            Self::Unix(listener) => listener.accept().map(|(stream, _)| Stream::Unix(stream)),
+        }
+    }
+}
+
+impl Stream {
+    pub fn try_clone(&self) -> io::Result<Self> {
+        match self {
+            Self::Tcp(stream) => stream.try_clone().map(Self::Tcp),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.try_clone().map(Self::Unix),
+        }
+    }
+
+    pub fn shutdown(&self) -> io::Result<()> {
+        match self {
+            Self::Tcp(stream) => stream.shutdown(Shutdown::Both),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.shutdown(Shutdown::Both),
+        }
+    }
+
+    pub fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        match self {
+            Self::Tcp(stream) => stream.set_write_timeout(timeout),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.set_write_timeout(timeout),
+        }
+    }
+}
+
+impl Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Tcp(stream) => stream.read(buf),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Tcp(stream) => stream.write(buf),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Tcp(stream) => stream.flush(),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Endpoint::parse("127.0.0.1:7777"),
+            Endpoint::Tcp("127.0.0.1:7777".to_string())
+        );
+        assert_eq!(
+            Endpoint::parse("unix:/tmp/snake.sock"),
+            Endpoint::Unix("/tmp/snake.sock".into())
+        );
+        assert_eq!(
+            Endpoint::parse("unix:/tmp/snake.sock").to_string(),
+            "unix:/tmp/snake.sock"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn bind_unix() {
+        let dir = std::env::temp_dir().join(format!("snake-endpoint-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("snake.sock");
+        let endpoint = Endpoint::Unix(path.clone());
+
+        // Not a socket
+        fs::write(&path, "precious").unwrap();
+        assert_eq!(
+            endpoint.bind().unwrap_err().kind(),
+            ErrorKind::AlreadyExists
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "precious");
+        fs::remove_file(&path).unwrap();
+
+        // Live socket
+        let listener = endpoint.bind().unwrap();
+        assert_eq!(endpoint.bind().unwrap_err().kind(), ErrorKind::AddrInUse);
+
+        // Stale socket
+        drop(listener);
+        assert!(endpoint.bind().is_ok());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,16 @@
+//! Multiplayer and spectating over TCP or Unix sockets
+
+mod broadcaster;
+mod client;
+mod endpoint;
+mod outbox;
+mod protocol;
+mod server;
+mod watcher;
+
+pub use broadcaster::*;
+pub use client::*;
+pub use endpoint::*;
+pub use protocol::*;
+pub use server::*;
+pub use watcher::*;
@@ -0,0 +1,52 @@
+use super::Stream;
+use crate::consts::FPS;
+use std::{
+    io::{self, Write},
+    sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError},
+    thread,
+};
+
+/// The number of lines queued for a peer before it is dropped as too slow.
+const MAX_QUEUED: usize = 2 * FPS as usize;
+
+/// The `Outbox` of a peer queues the lines a thread writes to it, so that
+/// slow peers hold back neither the others nor the game.
+#[derive(Debug)]
+pub(super) struct Outbox {
+    lines:  SyncSender<String>,
+    /// The connection, to close it when the peer is dropped.
+    stream: Stream,
+}
+
+impl Outbox {
+    /// Creates a new `Outbox` writing to `stream` from its own thread.
+    pub(super) fn new(stream: Stream) -> io::Result<Self> {
+        let (lines, queued) = sync_channel(MAX_QUEUED);
+        let writer = stream.try_clone()?;
+        thread::spawn(move || write_lines(writer, queued));
+
+        Ok(Self { lines, stream })
+    }
+
+    /// Queues `line`, failing when the peer is gone or too slow.
+    pub(super) fn send(&self, line: String) -> Result<(), TrySendError<String>> {
+        self.lines.try_send(line)
+    }
+
+    /// Closes the connection, dropping the lines still queued.
+    pub(super) fn close(&self) {
+        let _ = self.stream.shutdown();
+    }
+}
+
+/// Writes the `lines` queued for a peer, until it is dropped or fails.
+fn write_lines(mut stream: Stream, lines: Receiver<String>) {
+    for line in lines {
+        if writeln!(stream, "{}", line).is_err() {
+            break;
+        }
+    }
+
+    // A line may be written partially, no more can follow
+    let _ = stream.shutdown();
+}
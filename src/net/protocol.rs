@@ -0,0 +1,362 @@
+use crate::{
+    game::{Skin, SnakeSnapshot, Snapshot, Tile},
+    physics::{Direction, Point},
+};
+
+/// The version of the protocol, checked when clients say hello.
+pub const VERSION: u32 = 1;
+
+/// A message from a client to the server.
+///
+/// Messages are lines of space separated words, the first one being the
+/// message kind.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ClientMessage {
+    /// Joins the game, speaking `version` of the protocol.
+    Hello { version: u32 },
+    /// Steers to `direction`, having seen the snapshot of `tick`.
+    Steer {
+        tick:      u32,
+        direction: Direction,
+    },
+    /// Leaves the game.
+    Bye,
+}
+
+/// A message from the server to a client.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ServerMessage {
+    /// The client joined a `World` of `bounds` cells, playing `snake`.
+    Welcome { snake: usize, bounds: Point },
+    /// The client cannot join.
+    Refused { reason: String },
+    /// The client now plays `snake`, after dying.
+    Spawn { snake: usize },
+    /// The state of the `World`.
+    Snapshot(Snapshot),
+}
+
+impl ClientMessage {
+    /// Encodes this message as a line, without the newline.
+    pub fn encode(&self) -> String {
+        match self {
+            Self::Hello { version } => format!("HELLO {}", version),
+            Self::Steer { tick, direction } =>
+                format!("STEER {} {}", tick, encode_direction(*direction)),
+            Self::Bye => "BYE".to_string(),
+        }
+    }
+
+    /// Decodes a message from a line.
+    pub fn decode(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            Some("HELLO") => Ok(Self::Hello {
+                version: parse(words.next())?,
+            }),
+            Some("STEER") => Ok(Self::Steer {
+                tick:      parse(words.next())?,
+                direction: decode_direction(words.next())?,
+            }),
+            Some("BYE") => Ok(Self::Bye),
+            _ => Err(format!("Unknown message: {}", line)),
+        }
+    }
+}
+
+impl ServerMessage {
+    /// Encodes this message as a line, without the newline.
+    ///
+    /// Snapshots look like `SNAPSHOT <tick> <food> <terrain> <snake>...`, where
+    /// the food is a cell `x,y`, the terrain is `x,y,tile;x,y,tile...` (`-`
+    /// when empty) and each snake is `skin:alive:score:x,y;x,y...`, where the
+    /// skin is `P` for players and `O` for opponents.
+    pub fn encode(&self) -> String {
+        match self {
+            Self::Welcome { snake, bounds } =>
+                format!("WELCOME {} {}", snake, encode_point(*bounds)),
+            Self::Refused { reason } => format!("REFUSED {}", reason),
+            Self::Spawn { snake } => format!("SPAWN {}", snake),
+            Self::Snapshot(snapshot) => {
+                let terrain = snapshot
+                    .terrain
+                    .iter()
+                    .map(|(cell, tile)| format!("{},{}", encode_point(*cell), encode_tile(*tile)))
+                    .collect::<Vec<_>>()
+                    .join(";");
+                let mut line = format!(
+                    "SNAPSHOT {} {} {}",
+                    snapshot.tick,
+                    encode_point(snapshot.food),
+                    if terrain.is_empty() { "-" } else { &terrain }
+                );
+
+                for snake in &snapshot.snakes {
+                    let body = snake
+                        .body
+                        .iter()
+                        .map(|point| encode_point(*point))
+                        .collect::<Vec<_>>()
+                        .join(";");
+
+                    line += &format!(
+                        " {}:{}:{}:{}",
+                        encode_skin(snake.skin),
+                        snake.alive as u8,
+                        snake.score,
+                        body
+                    );
+                }
+
+                line
+            }
+        }
+    }
+
+    /// Decodes a message from a line.
+    pub fn decode(line: &str) -> Result<Self, String> {
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            Some("WELCOME") => Ok(Self::Welcome {
+                snake:  parse(words.next())?,
+                bounds: decode_point(words.next())?,
+            }),
+            Some("REFUSED") => Ok(Self::Refused {
+                reason: words.collect::<Vec<_>>().join(" "),
+            }),
+            Some("SPAWN") => Ok(Self::Spawn {
+                snake: parse(words.next())?,
+            }),
+            Some("SNAPSHOT") => {
+                let tick = parse(words.next())?;
+                let food = decode_point(words.next())?;
+                let terrain = decode_terrain(words.next())?;
+                let snakes = words.map(decode_snake).collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Self::Snapshot(Snapshot {
+                    tick,
+                    food,
+                    terrain,
+                    snakes,
+                }))
+            }
+            _ => Err(format!("Unknown message: {}", line)),
+        }
+    }
+}
+
+fn parse<T: std::str::FromStr>(word: Option<&str>) -> Result<T, String> {
+    let word = word.ok_or("Missing field")?;
+
+    word.parse().map_err(|_| format!("Invalid field: {}", word))
+}
+
+fn encode_direction(direction: Direction) -> &'static str {
+    match direction {
+        Direction::Up => "U",
+        Direction::Down => "D",
+        Direction::Left => "L",
+        Direction::Right => "R",
+        Direction::UpLeft => "UL",
+        Direction::UpRight => "UR",
+        Direction::DownLeft => "DL",
+        Direction::DownRight => "DR",
+    }
+}
+
+fn decode_direction(word: Option<&str>) -> Result<Direction, String> {
+    match word {
+        Some("U") => Ok(Direction::Up),
+        Some("D") => Ok(Direction::Down),
+        Some("L") => Ok(Direction::Left),
+        Some("R") => Ok(Direction::Right),
+        Some("UL") => Ok(Direction::UpLeft),
+        Some("UR") => Ok(Direction::UpRight),
+        Some("DL") => Ok(Direction::DownLeft),
+        Some("DR") => Ok(Direction::DownRight),
+        _ => Err(format!("Invalid direction: {:?}", word)),
+    }
+}
+
+fn encode_point(point: Point) -> String {
+    format!("{},{}", point.x, point.y)
+}
+
+fn decode_point(word: Option<&str>) -> Result<Point, String> {
+    let word = word.ok_or("Missing point")?;
+    let mut coords = word.splitn(2, ',');
+    let point = Point::new(parse(coords.next())?, parse(coords.next())?);
+
+    if [point.x, point.y]
+        .iter()
+        .all(|coord| coord.is_finite() && *coord >= 0.0)
+    {
+        Ok(point)
+    } else {
+        Err(format!("Invalid point: {}", word))
+    }
+}
+
+fn encode_skin(skin: Skin) -> char {
+    match skin {
+        Skin::Player => 'P',
+        Skin::Opponent => 'O',
+    }
+}
+
+fn decode_skin(word: Option<&str>) -> Result<Skin, String> {
+    match word.ok_or("Missing skin")? {
+        "P" => Ok(Skin::Player),
+        "O" => Ok(Skin::Opponent),
+        word => Err(format!("Unknown skin {}", word)),
+    }
+}
+
+fn encode_tile(tile: Tile) -> char {
+    match tile {
+        Tile::Floor => 'F',
+        Tile::Wall => 'W',
+        Tile::Rock => 'R',
+        Tile::Portal => 'P',
+    }
+}
+
+fn decode_terrain(word: Option<&str>) -> Result<Vec<(Point, Tile)>, String> {
+    match word.ok_or("Missing terrain")? {
+        "-" => Ok(vec![]),
+        word => word
+            .split(';')
+            .map(|cell| {
+                let (point, tile) = cell
+                    .rsplit_once(',')
+                    .ok_or_else(|| format!("Invalid cell: {}", cell))?;
+                let tile = match tile {
+                    "F" => Tile::Floor,
+                    "W" => Tile::Wall,
+                    "R" => Tile::Rock,
+                    "P" => Tile::Portal,
+                    _ => return Err(format!("Invalid tile: {}", tile)),
+                };
+
+                Ok((decode_point(Some(point))?, tile))
+            })
+            .collect(),
+    }
+}
+
+fn decode_snake(word: &str) -> Result<SnakeSnapshot, String> {
+    let mut fields = word.splitn(4, ':');
+    let skin = decode_skin(fields.next())?;
+    let alive = parse::<u8>(fields.next())? != 0;
+    let score = parse(fields.next())?;
+    let body = fields
+        .next()
+        .ok_or("Missing body")?
+        .split(';')
+        .filter(|point| !point.is_empty())
+        .map(|point| decode_point(Some(point)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(SnakeSnapshot {
+        skin,
+        alive,
+        score,
+        body,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn client_messages() {
+        let messages = [
+            ClientMessage::Hello { version: VERSION },
+            ClientMessage::Steer {
+                tick:      12,
+                direction: Direction::Left,
+            },
+            ClientMessage::Steer {
+                tick:      13,
+                direction: Direction::DownRight,
+            },
+            ClientMessage::Bye,
+        ];
+
+        for message in messages.iter() {
+            assert_eq!(&ClientMessage::decode(&message.encode()).unwrap(), message);
+        }
+        assert!(ClientMessage::decode("STEER 12 X").is_err());
+        assert!(ClientMessage::decode("JUMP").is_err());
+    }
+
+    #[test]
+    fn server_messages() {
+        let messages = [
+            ServerMessage::Welcome {
+                snake:  2,
+                bounds: Point::new(40.0, 20.0),
+            },
+            ServerMessage::Refused {
+                reason: "Unsupported version 9".to_string(),
+            },
+            ServerMessage::Spawn { snake: 4 },
+            ServerMessage::Snapshot(Snapshot {
+                tick:    7,
+                food:    Point::new(3.0, 4.0),
+                terrain: vec![
+                    (Point::new(0.0, 0.0), Tile::Wall),
+                    (Point::new(8.0, 2.0), Tile::Rock),
+                    (Point::new(9.0, 2.0), Tile::Portal),
+                ],
+                snakes:  vec![
+                    SnakeSnapshot {
+                        skin:  Skin::Player,
+                        alive: true,
+                        score: 20,
+                        body:  vec![Point::new(1.0, 1.0), Point::new(0.0, 1.0)],
+                    },
+                    SnakeSnapshot {
+                        skin:  Skin::Opponent,
+                        alive: false,
+                        score: 0,
+                        body:  vec![Point::new(5.0, 5.0)],
+                    },
+                ],
+            }),
+        ];
+
+        for message in messages.iter() {
+            assert_eq!(&ServerMessage::decode(&message.encode()).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn snapshot_line() {
+        let message = ServerMessage::Snapshot(Snapshot {
+            tick:    1,
+            food:    Point::new(3.0, 4.0),
+            terrain: vec![],
+            snakes:  vec![SnakeSnapshot {
+                skin:  Skin::Player,
+                alive: true,
+                score: 10,
+                body:  vec![Point::new(1.0, 2.0), Point::new(0.0, 2.0)],
+            }],
+        });
+
+        assert_eq!(message.encode(), "SNAPSHOT 1 3,4 - P:1:10:1,2;0,2");
+    }
+
+    #[test]
+    fn invalid_points() {
+        assert!(ServerMessage::decode("WELCOME 0 NaN,20").is_err());
+        assert!(ServerMessage::decode("WELCOME 0 40,-1").is_err());
+        assert!(ServerMessage::decode("SNAPSHOT 1 inf,4 - P:1:10:1,2").is_err());
+        assert!(ServerMessage::decode("SNAPSHOT 1 3,4 - P:1:10:-1,2").is_err());
+    }
+}
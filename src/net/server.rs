@@ -0,0 +1,388 @@
+use super::{outbox::Outbox, ClientMessage, ServerMessage, Stream, VERSION};
+use crate::{
+    consts::FPS,
+    game::{Config, Level, World},
+    physics::Direction,
+};
+use std::{
+    collections::BTreeMap,
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::mpsc::{channel, Receiver, Sender},
+    thread::{self, sleep},
+    time::{Duration, Instant},
+};
+
+/// Inputs seen more than this number of ticks ago are dropped.
+const MAX_INPUT_LAG: u32 = 5;
+
+/// The number of ticks a dead player waits before playing again.
+const RESPAWN_TICKS: u32 = 3 * FPS as u32;
+
+/// How long a write to a peer may block before it is dropped.
+const WRITE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// An `Event` from the connection threads.
+#[derive(Debug)]
+enum Event {
+    Join(usize, Outbox),
+    Steer(usize, u32, Direction),
+    Leave(usize),
+}
+
+/// A `Peer` is a connected client.
+#[derive(Debug)]
+struct Peer {
+    outbox:     Outbox,
+    snake:      usize,
+    dead_since: Option<u32>,
+}
+
+/// The authoritative `Server` owns the `World` and plays the inputs of its
+/// peers.
+#[derive(Debug)]
+pub struct Server {
+    addr:   SocketAddr,
+    world:  World,
+    events: Receiver<Event>,
+    peers:  BTreeMap<usize, Peer>,
+    /// The snakes of the peers who left, played by the next ones to join.
+    free:   Vec<usize>,
+}
+
+impl Server {
+    /// Creates a new `Server` listening on `addr`, with an empty `World`.
+    pub fn bind(
+        addr: impl ToSocketAddrs,
+        config: Config,
+        seed: u64,
+        level: &Level,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let (sender, events) = channel();
+
+        thread::spawn(move || accept(listener, sender));
+
+        Ok(Self {
+            addr,
+            world: World::with_players(config, seed, level, 0),
+            events,
+            peers: BTreeMap::new(),
+            free: vec![],
+        })
+    }
+
+    /// Returns the address the `Server` listens on.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Returns the number of connected peers.
+    pub fn peers(&self) -> usize {
+        self.peers.len()
+    }
+
+    /// Runs the game forever.
+    pub fn run(&mut self) {
+        let mpf = Duration::from_millis(1000 / FPS);
+        println!("Listening on {}", self.addr);
+
+        loop {
+            let start = Instant::now();
+
+            self.tick();
+
+            let elapsed = start.elapsed();
+            if elapsed < mpf {
+                sleep(mpf - elapsed);
+            }
+        }
+    }
+
+    /// Handles the events, updates the `World` and sends a snapshot to the
+    /// peers.
+    pub fn tick(&mut self) {
+        while let Ok(event) = self.events.try_recv() {
+            self.handle(event);
+        }
+
+        self.respawn();
+        self.world.update();
+
+        let snapshot = ServerMessage::Snapshot(self.world.snapshot());
+        let ids = self.peers.keys().cloned().collect::<Vec<_>>();
+        for id in ids {
+            self.send(id, &snapshot);
+        }
+    }
+
+    fn handle(&mut self, event: Event) {
+        match event {
+            Event::Join(id, outbox) => {
+                let snake = match self.free.pop() {
+                    Some(snake) if self.world.respawn(snake) => Some(snake),
+                    Some(snake) => {
+                        self.free.push(snake);
+                        None
+                    }
+                    None => self.world.add_player(),
+                };
+                let snake = match snake {
+                    Some(snake) => snake,
+                    None => {
+                        let reason = "No room left".to_string();
+                        let _ = outbox.send(ServerMessage::Refused { reason }.encode());
+                        return;
+                    }
+                };
+                let bounds = self.world.bounds();
+
+                println!("Player {} joined", id);
+                self.peers.insert(id, Peer {
+                    outbox,
+                    snake,
+                    dead_since: None,
+                });
+                self.send(id, &ServerMessage::Welcome { snake, bounds });
+            }
+            Event::Steer(id, tick, direction) => {
+                // Late inputs would steer from a state the player did not see
+                if self.world.tick().saturating_sub(tick) > MAX_INPUT_LAG {
+                    return;
+                }
+
+                if let Some(peer) = self.peers.get(&id) {
+                    self.world.steer_snake(peer.snake, direction);
+                }
+            }
+            Event::Leave(id) => self.leave(id),
+        }
+    }
+
+    fn respawn(&mut self) {
+        let tick = self.world.tick();
+        let mut spawned = vec![];
+
+        for (id, peer) in self.peers.iter_mut() {
+            if self.world.snakes()[peer.snake].is_alive() {
+                continue;
+            }
+
+            let dead_since = *peer.dead_since.get_or_insert(tick);
+            // Without room, try again next tick
+            if tick - dead_since >= RESPAWN_TICKS && self.world.respawn(peer.snake) {
+                peer.dead_since = None;
+                spawned.push((*id, peer.snake));
+            }
+        }
+
+        for (id, snake) in spawned {
+            self.send(id, &ServerMessage::Spawn { snake });
+        }
+    }
+
+    /// Queues `message` for the peer `id`, dropping it when its queue is
+    /// full.
+    fn send(&mut self, id: usize, message: &ServerMessage) {
+        if let Some(peer) = self.peers.get(&id) {
+            if peer.outbox.send(message.encode()).is_err() {
+                self.leave(id);
+            }
+        }
+    }
+
+    fn leave(&mut self, id: usize) {
+        if let Some(peer) = self.peers.remove(&id) {
+            println!("Player {} left", id);
+            peer.outbox.close();
+            self.world.kill(peer.snake);
+            self.free.push(peer.snake);
+        }
+    }
+}
+
+/// Accepts connections, each in its own thread.
+fn accept(listener: TcpListener, sender: Sender<Event>) {
+    for (id, stream) in listener.incoming().enumerate() {
+        if let Ok(stream) = stream {
+            let sender = sender.clone();
+
+            thread::spawn(move || {
+                let _ = serve(id, stream, &sender);
+                let _ = sender.send(Event::Leave(id));
+            });
+        }
+    }
+}
+
+/// Reads the messages of a peer until it leaves.
+fn serve(id: usize, mut stream: TcpStream, sender: &Sender<Event>) -> io::Result<()> {
+    stream.set_nodelay(true)?;
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let mut lines = BufReader::new(stream.try_clone()?).lines();
+
+    match lines
+        .next()
+        .transpose()?
+        .map(|line| ClientMessage::decode(&line))
+    {
+        Some(Ok(ClientMessage::Hello { version })) if version == VERSION => {}
+        Some(Ok(ClientMessage::Hello { version })) => {
+            let reason = format!("Unsupported version {}, expected {}", version, VERSION);
+            return writeln!(stream, "{}", ServerMessage::Refused { reason }.encode());
+        }
+        _ => return Ok(()),
+    }
+
+    let outbox = Outbox::new(Stream::Tcp(stream))?;
+    if sender.send(Event::Join(id, outbox)).is_err() {
+        return Ok(());
+    }
+
+    for line in lines {
+        match ClientMessage::decode(&line?) {
+            Ok(ClientMessage::Steer { tick, direction })
+                if sender.send(Event::Steer(id, tick, direction)).is_err() =>
+                break,
+            Ok(ClientMessage::Bye) => break,
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{game::SpeedCurve, net::Client};
+    use pretty_assertions::assert_eq;
+
+    fn server() -> Server {
+        let level = Level {
+            name:      "Test",
+            opponents: &[],
+            obstacles: &[],
+            speed:     SpeedCurve::constant(10.0),
+            goal:      None,
+        };
+        let config = Config::new((20.0, 10.0), Duration::from_millis(200));
+
+        Server::bind("127.0.0.1:0", config, 1, &level).unwrap()
+    }
+
+    /// Ticks `server` until `done`.
+    fn tick_until(server: &mut Server, mut done: impl FnMut(&mut Server) -> bool) {
+        for _ in 0..200 {
+            server.tick();
+            if done(server) {
+                return;
+            }
+            sleep(Duration::from_millis(5));
+        }
+
+        panic!("Timed out");
+    }
+
+    /// Connects a `Client` to `server`, ticking it until the client joined.
+    fn connect(server: &mut Server) -> Client {
+        let addr = server.local_addr();
+        let client = thread::spawn(move || Client::connect(addr));
+
+        tick_until(server, |server| server.peers() == 1);
+        client.join().unwrap().unwrap()
+    }
+
+    #[test]
+    fn join_steer_leave() {
+        let mut server = server();
+        let mut client = connect(&mut server);
+        assert_eq!(client.bounds(), server.world.bounds());
+
+        let snake = client.snake();
+        let mut snapshot = None;
+        while snapshot.is_none() {
+            server.tick();
+            snapshot = client.poll().unwrap();
+        }
+        let snapshot = snapshot.unwrap();
+        assert!(snapshot.snakes[snake].alive);
+
+        client.steer(snapshot.tick, Direction::Up).unwrap();
+        tick_until(&mut server, |server| {
+            server.world.snakes()[snake].direction() == Direction::Up
+        });
+
+        drop(client);
+        tick_until(&mut server, |server| server.peers() == 0);
+        assert!(!server.world.snakes()[snake].is_alive());
+    }
+
+    #[test]
+    fn reuses_snakes() {
+        let mut server = server();
+        let client = connect(&mut server);
+        let snake = client.snake();
+
+        server.world.kill(snake);
+        tick_until(&mut server, |server| {
+            server.world.snakes()[snake].is_alive()
+        });
+        assert_eq!(server.world.snakes().len(), 1);
+
+        drop(client);
+        tick_until(&mut server, |server| server.peers() == 0);
+        let client = connect(&mut server);
+        assert_eq!(client.snake(), snake);
+        assert_eq!(server.world.snakes().len(), 1);
+    }
+
+    #[test]
+    fn late_inputs() {
+        let mut server = server();
+        let mut client = connect(&mut server);
+        for _ in 0..MAX_INPUT_LAG + 2 {
+            server.tick();
+        }
+
+        client.steer(0, Direction::Up).unwrap();
+        client.steer(server.world.tick(), Direction::Down).unwrap();
+        tick_until(&mut server, |server| {
+            server.world.snakes()[client.snake()].direction() != Direction::Right
+        });
+        assert_eq!(
+            server.world.snakes()[client.snake()].direction(),
+            Direction::Down
+        );
+    }
+
+    #[test]
+    fn drops_slow_peers() {
+        let mut server = server();
+        let mut stream = TcpStream::connect(server.local_addr()).unwrap();
+        writeln!(stream, "HELLO {}", VERSION).unwrap();
+        tick_until(&mut server, |server| server.peers() == 1);
+
+        // Never read: the buffers, then the queue, fill up
+        let start = Instant::now();
+        while server.peers() == 1 {
+            server.tick();
+            assert!(start.elapsed() < Duration::from_secs(60), "Timed out");
+        }
+    }
+
+    #[test]
+    fn refuses_other_versions() {
+        let server = server();
+        let mut stream = TcpStream::connect(server.local_addr()).unwrap();
+        writeln!(stream, "HELLO {}", VERSION + 1).unwrap();
+
+        let mut line = String::new();
+        BufReader::new(stream).read_line(&mut line).unwrap();
+        assert!(matches!(
+            ServerMessage::decode(&line),
+            Ok(ServerMessage::Refused { .. })
+        ));
+    }
+}
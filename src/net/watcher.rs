@@ -0,0 +1,62 @@
+use super::{
+    client::{read_welcome, receive},
+    Endpoint,
+    ServerMessage,
+};
+use crate::{game::Snapshot, physics::Point};
+use std::{
+    io::{self, BufReader, Error, ErrorKind},
+    sync::mpsc::{Receiver, TryRecvError},
+};
+
+/// A `Watcher` follows a game published by a `Broadcaster`, read-only.
+#[derive(Debug)]
+pub struct Watcher {
+    snake:    usize,
+    bounds:   Point,
+    messages: Receiver<ServerMessage>,
+}
+
+impl Watcher {
+    /// Connects to the `Broadcaster` at `endpoint`.
+    pub fn connect(endpoint: &Endpoint) -> io::Result<Self> {
+        let mut reader = BufReader::new(endpoint.connect()?);
+        let (snake, bounds) = read_welcome(&mut reader)?;
+        let messages = receive(reader);
+
+        Ok(Self {
+            snake,
+            bounds,
+            messages,
+        })
+    }
+
+    /// Returns the index of the watched snake in the snapshots.
+    pub fn snake(&self) -> usize {
+        self.snake
+    }
+
+    /// Returns the size of the `World`, in cells.
+    pub fn bounds(&self) -> Point {
+        self.bounds
+    }
+
+    /// Returns the latest received `Snapshot`, if any. Snapshots with cells
+    /// out of the bounds are dropped.
+    ///
+    /// Fails when the game is over.
+    pub fn poll(&mut self) -> io::Result<Option<Snapshot>> {
+        let mut snapshot = None;
+
+        loop {
+            match self.messages.try_recv() {
+                Ok(ServerMessage::Snapshot(latest)) if latest.fits(self.bounds) =>
+                    snapshot = Some(latest),
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => return Ok(snapshot),
+                Err(TryRecvError::Disconnected) =>
+                    return Err(Error::new(ErrorKind::ConnectionAborted, "Game is over")),
+            }
+        }
+    }
+}
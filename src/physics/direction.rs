@@ -1,10 +1,11 @@
+#[allow(unused_imports)]
 use super::{Point, Speed, Speed2D};
 use std::{
     fmt::{Debug, Error, Formatter},
     ops::Neg,
 };
 
-/// A type to represent the four `Direction`s.
+/// A type to represent the four `Direction`s, and the four diagonals.
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum Direction {
     /// The up `Direction`.
@@ -15,6 +16,57 @@ pub enum Direction {
     Left,
     /// The right `Direction`.
     Right,
+    /// The up-left diagonal `Direction`.
+    UpLeft,
+    /// The up-right diagonal `Direction`.
+    UpRight,
+    /// The down-left diagonal `Direction`.
+    DownLeft,
+    /// The down-right diagonal `Direction`.
+    DownRight,
+}
+
+impl Direction {
+    /// All the `Direction`s, without diagonals.
+    pub const ALL: [Direction; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+    /// All the `Direction`s, diagonals last.
+    pub const EIGHT: [Direction; 8] = [
+        Self::Up,
+        Self::Down,
+        Self::Left,
+        Self::Right,
+        Self::UpLeft,
+        Self::UpRight,
+        Self::DownLeft,
+        Self::DownRight,
+    ];
+
+    /// Returns this `Direction` rotated by 90° counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Left => Self::Down,
+            Self::Down => Self::Right,
+            Self::Right => Self::Up,
+            Self::UpLeft => Self::DownLeft,
+            Self::DownLeft => Self::DownRight,
+            Self::DownRight => Self::UpRight,
+            Self::UpRight => Self::UpLeft,
+        }
+    }
+
+    /// Returns this `Direction` rotated by 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        -self.turn_left()
+    }
+
+    /// Returns whether this `Direction` is a diagonal.
+    pub fn is_diagonal(self) -> bool {
+        matches!(
+            self,
+            Self::UpLeft | Self::UpRight | Self::DownLeft | Self::DownRight
+        )
+    }
 }
 
 /// Returns the opposite `Direction`.
@@ -27,6 +79,10 @@ impl Neg for Direction {
             Self::Down => Self::Up,
             Self::Left => Self::Right,
             Self::Right => Self::Left,
+            Self::UpLeft => Self::DownRight,
+            Self::UpRight => Self::DownLeft,
+            Self::DownLeft => Self::UpRight,
+            Self::DownRight => Self::UpLeft,
         }
     }
 }
@@ -38,6 +94,10 @@ impl Debug for Direction {
             Self::Down => write!(f, "↓"),
             Self::Left => write!(f, "←"),
             Self::Right => write!(f, "→"),
+            Self::UpLeft => write!(f, "↖"),
+            Self::UpRight => write!(f, "↗"),
+            Self::DownLeft => write!(f, "↙"),
+            Self::DownRight => write!(f, "↘"),
         }
     }
 }
@@ -53,5 +113,23 @@ mod tests {
         assert_eq!(-Direction::Down, Direction::Up);
         assert_eq!(-Direction::Left, Direction::Right);
         assert_eq!(-Direction::Right, Direction::Left);
+        assert_eq!(-Direction::UpLeft, Direction::DownRight);
+        assert_eq!(-Direction::DownLeft, Direction::UpRight);
+    }
+
+    #[test]
+    fn turn() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::UpRight.turn_left(), Direction::UpLeft);
+        assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+
+        for direction in Direction::EIGHT.iter() {
+            assert_eq!(direction.turn_left().turn_right(), *direction);
+            assert_eq!(
+                direction.turn_left().turn_left().turn_left().turn_left(),
+                *direction
+            );
+        }
     }
 }
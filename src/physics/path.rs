@@ -32,6 +32,7 @@ impl<T: Iterator<Item = PathFragment>> Path<T> {
 }
 
 impl<T: Iterator<Item = PathFragment>> Moving for Path<T> {
+    #[allow(clippy::while_let_loop)]
     fn r#move(&mut self, mut duration: Duration) {
         loop {
             if let Some(fragment) = &mut self.current {
@@ -14,6 +14,11 @@ impl Point {
         Self::new(self.x.trunc(), self.y.trunc())
     }
 
+    /// Wraps the coordinates into `[0, bounds)`, as on a torus.
+    pub fn wrap(&self, bounds: Point) -> Self {
+        Self::new(self.x.rem_euclid(bounds.x), self.y.rem_euclid(bounds.y))
+    }
+
     /// Returns the euclidian `Distance` from the origin.
     pub fn length(&self) -> Distance {
         Distance::sqrt(self.x * self.x + self.y * self.y)
@@ -27,6 +32,10 @@ impl From<Direction> for Point {
             Direction::Down => (0.0, 1.0),
             Direction::Left => (-1.0, 0.0),
             Direction::Right => (1.0, 0.0),
+            Direction::UpLeft => (-1.0, -1.0),
+            Direction::UpRight => (1.0, -1.0),
+            Direction::DownLeft => (-1.0, 1.0),
+            Direction::DownRight => (1.0, 1.0),
         }
         .into()
     }
@@ -44,6 +53,15 @@ mod tests {
 
     // TODO: round, trunc
 
+    #[test]
+    fn wrap() {
+        let bounds = Point::new(10.0, 5.0);
+
+        assert_eq!(Point::new(3.0, 2.0).wrap(bounds), Point::new(3.0, 2.0));
+        assert_eq!(Point::new(10.0, 5.0).wrap(bounds), Point::new(0.0, 0.0));
+        assert_eq!(Point::new(-1.0, -2.0).wrap(bounds), Point::new(9.0, 3.0));
+    }
+
     #[test]
     fn from_direction() {
         let from = Point::from;
@@ -1,3 +1,4 @@
+#[allow(unused_imports)]
 use super::{Coord2D, Direction, Distance, Duration, Point, Speed};
 
 /// A `Speed2D` type (`Coord2D<Speed<Distance>>`).
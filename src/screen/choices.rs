@@ -0,0 +1,116 @@
+use crate::{
+    color::PALETTE,
+    events::KeyCode,
+    theme::{Theme, MARKER_WIDTH},
+    width::max_width,
+};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{
+        Attribute,
+        Color,
+        Print,
+        ResetColor,
+        SetAttribute,
+        SetBackgroundColor,
+        SetForegroundColor,
+    },
+    terminal::{Clear, ClearType},
+};
+use std::io::Write;
+
+/// A vertical list of `Choices`, one of them selected, drawn with the
+/// markers of a theme.
+#[derive(Clone, Debug)]
+pub struct Choices {
+    len:      usize,
+    selected: usize,
+}
+
+impl Choices {
+    /// Creates `Choices` between `len` items, the first one selected.
+    pub fn new(len: usize) -> Self {
+        Self { len, selected: 0 }
+    }
+
+    /// Returns the index of the selected item.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Moves the selection on `Up` and `Down`, wrapping around.
+    ///
+    /// Returns whether `code` was handled.
+    pub fn handle(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Up => self.selected = (self.selected + self.len - 1) % self.len,
+            KeyCode::Down => self.selected = (self.selected + 1) % self.len,
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Returns the width of the `Choices` between `items`, with markers.
+    pub fn width<'a>(items: impl IntoIterator<Item = &'a str>) -> u16 {
+        MARKER_WIDTH + max_width(items)
+    }
+
+    /// Queues `items` on `out` with the markers of `theme`, one per row from
+    /// `(x, y)`.
+    pub fn draw<'a>(
+        &self,
+        out: &mut (impl Write + ?Sized),
+        theme: &Theme,
+        x: u16,
+        y: u16,
+        items: impl IntoIterator<Item = &'a str>,
+    ) {
+        for (i, item) in items.into_iter().enumerate() {
+            let (before, attr, fg, bg) = if i == self.selected {
+                (
+                    theme.selected,
+                    Attribute::Bold,
+                    PALETTE.highlight_text.color(),
+                    PALETTE.highlight.color(),
+                )
+            } else {
+                (theme.item, Attribute::Reset, Color::Reset, Color::Reset)
+            };
+
+            queue!(
+                out,
+                MoveTo(x, y + i as u16),
+                Clear(ClearType::UntilNewLine),
+                Print(before),
+                SetAttribute(attr),
+                SetForegroundColor(fg),
+                SetBackgroundColor(bg),
+                Print(item),
+                ResetColor,
+                SetAttribute(Attribute::Reset),
+            )
+            .unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn wraps_around() {
+        let mut choices = Choices::new(3);
+
+        assert!(choices.handle(KeyCode::Up));
+        assert_eq!(choices.selected(), 2);
+        assert!(choices.handle(KeyCode::Down));
+        assert!(choices.handle(KeyCode::Down));
+        assert_eq!(choices.selected(), 1);
+        assert!(!choices.handle(KeyCode::Enter));
+        assert_eq!(choices.selected(), 1);
+    }
+}
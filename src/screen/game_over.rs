@@ -0,0 +1,108 @@
+use super::Choices;
+use crate::{
+    events::KeyCode,
+    game::Outcome,
+    theme::Theme,
+    width::{center, max_width, width},
+};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Print, SetAttribute},
+};
+use std::io::Write;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum GameOverAction {
+    /// Plays the same level again, with the same seed.
+    Retry,
+    /// Plays the same level again, with a new seed.
+    RetryNewSeed,
+    Menu,
+    Quit,
+}
+
+/// The items of the `GameOver` screen, in order.
+const ITEMS: [(GameOverAction, &str); 4] = [
+    (GameOverAction::Retry, "Retry"),
+    (GameOverAction::RetryNewSeed, "Retry with a new seed"),
+    (GameOverAction::Menu, "Menu"),
+    (GameOverAction::Quit, "Quit"),
+];
+
+/// The `GameOver` screen sums up a finished game.
+#[derive(Debug)]
+pub struct GameOver {
+    width:   u16,
+    height:  u16,
+    choices: Choices,
+}
+
+impl GameOver {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            choices: Choices::new(ITEMS.len()),
+        }
+    }
+
+    /// Draws `outcome` and the actions with `theme`.
+    pub fn render(&self, out: &mut (impl Write + ?Sized), outcome: &Outcome, theme: &Theme) {
+        let title = if outcome.best {
+            "Game over - new personal best!"
+        } else {
+            "Game over"
+        };
+        let rows = [
+            ("Cause", outcome.cause().to_string()),
+            ("Score", outcome.score.to_string()),
+            ("Length", outcome.length.to_string()),
+        ];
+        let label_width = max_width(rows.iter().map(|(label, _)| *label)) + 2;
+        let items_width = Choices::width(ITEMS.iter().map(|(_, item)| *item));
+        let content_width = width(title)
+            .max(items_width)
+            .max(label_width + max_width(rows.iter().map(|(_, value)| value.as_str())));
+        let x = center(self.width, content_width);
+        let y = center(self.height, 2 + rows.len() as u16 + 1 + ITEMS.len() as u16);
+        let items_y = y + 2 + rows.len() as u16 + 1;
+
+        queue!(
+            out,
+            MoveTo(x, y),
+            SetAttribute(Attribute::Bold),
+            Print(title),
+            SetAttribute(Attribute::Reset),
+        )
+        .unwrap();
+        for (i, (label, value)) in rows.iter().enumerate() {
+            queue!(
+                out,
+                MoveTo(x, y + 2 + i as u16),
+                Print(label),
+                MoveTo(x + label_width, y + 2 + i as u16),
+                SetAttribute(Attribute::Bold),
+                Print(value),
+                SetAttribute(Attribute::Reset),
+            )
+            .unwrap();
+        }
+
+        self.choices
+            .draw(out, theme, x, items_y, ITEMS.iter().map(|(_, item)| *item));
+        out.flush().unwrap();
+    }
+
+    /// Handles `code`, returning a `GameOverAction` once one is chosen.
+    ///
+    /// `Esc` goes back to the menu.
+    pub fn handle(&mut self, code: KeyCode) -> Option<GameOverAction> {
+        match code {
+            _ if self.choices.handle(code) => None,
+            KeyCode::Enter => Some(ITEMS[self.choices.selected()].0),
+            KeyCode::Esc => Some(GameOverAction::Menu),
+            _ => None,
+        }
+    }
+}
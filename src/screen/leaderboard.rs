@@ -0,0 +1,81 @@
+use crate::{
+    game::{HighScores, Level},
+    width::{center, max_width, width},
+};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Print, SetAttribute},
+};
+use std::io::Write;
+
+const EMPTY: &str = "No high scores yet";
+
+/// The `Leaderboard` screen lists the high scores.
+#[derive(Debug)]
+pub struct Leaderboard {
+    width:  u16,
+    height: u16,
+}
+
+impl Leaderboard {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self { width, height }
+    }
+
+    /// Draws `high_scores`, until a key is pressed.
+    pub fn render(&self, out: &mut (impl Write + ?Sized), high_scores: &HighScores) {
+        let rows = high_scores
+            .entries()
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                (
+                    format!("{:>2}. {}", i + 1, entry.name),
+                    entry.score.to_string(),
+                    format!("{} - {}", Level::get(entry.level).name, entry.mode.name()),
+                )
+            })
+            .collect::<Vec<_>>();
+        let name_width = max_width(rows.iter().map(|(name, ..)| &name[..]));
+        let score_width = max_width(rows.iter().map(|(_, score, _)| &score[..]));
+        let game_width = max_width(rows.iter().map(|(.., game)| &game[..]));
+        let x = center(
+            self.width,
+            (name_width + 2 + score_width + 2 + game_width).max(width(EMPTY)),
+        );
+        let y = center(self.height, rows.len().max(1) as u16 + 2);
+
+        if rows.is_empty() {
+            queue!(out, MoveTo(x, y), Print(EMPTY)).unwrap();
+        }
+        for (i, (name, score, game)) in rows.iter().enumerate() {
+            // Padded by display width, as `format!` pads by characters
+            let name_padding = " ".repeat(usize::from(name_width - width(name) + 2));
+            let score_padding = " ".repeat(usize::from(score_width - width(score)));
+
+            queue!(
+                out,
+                MoveTo(x, y + i as u16),
+                Print(name),
+                Print(name_padding),
+                SetAttribute(Attribute::Bold),
+                Print(score_padding),
+                Print(score),
+                SetAttribute(Attribute::Reset),
+                Print("  "),
+                SetAttribute(Attribute::Dim),
+                Print(game),
+                SetAttribute(Attribute::Reset),
+            )
+            .unwrap();
+        }
+        queue!(
+            out,
+            MoveTo(x, y + rows.len().max(1) as u16 + 1),
+            Print("[Press any key to go back]"),
+        )
+        .unwrap();
+        out.flush().unwrap();
+    }
+}
@@ -1,72 +1,81 @@
 use crate::{
-    consts::{KEYS, MENU_FPS, SNAKE, SNAKE_HEIGHT, SNAKE_WIDTH},
-    events::{poll, Event, KeyCode, KeyEvent},
+    color::PALETTE,
+    consts::{KEYS, SNAKE, SNAKE_HEIGHT},
+    events::KeyCode,
+    game::{GameMode, LEVELS},
+    theme::{Theme, MARKER_WIDTH},
+    width::{center, max_width, width},
 };
 use crossterm::{
     cursor::MoveTo,
     queue,
-    style::{Attribute, Print, SetAttribute},
+    style::{
+        Attribute,
+        Color,
+        Print,
+        ResetColor,
+        SetAttribute,
+        SetBackgroundColor,
+        SetForegroundColor,
+    },
     terminal::{Clear, ClearType},
 };
-use std::{
-    io::{stdout, Stdout, Write},
-    thread::sleep,
-    time::Duration,
-};
+use std::io::Write;
 
 #[derive(Debug)]
 pub enum MenuAction {
-    Level(u8),
+    Level(u8, GameMode),
+    Achievements,
+    Stats,
+    HighScores,
+    Settings,
     Quit,
 }
 
 #[derive(Debug)]
 pub struct Menu {
-    out:      Stdout,
-    levels:   [&'static str; 5],
-    selected: u8,
-    max:      u8,
-    snake_x:  u16,
-    snake_y:  u16,
-    levels_x: u16,
-    levels_y: u16,
-    keys_x:   u16,
-    keys_y:   u16,
+    levels:       Vec<String>,
+    selected:     u8,
+    max:          u8,
+    mode:         GameMode,
+    snake_x:      u16,
+    snake_y:      u16,
+    /// Where the snake is while it slides to its place, if it does.
+    snake_y_anim: Option<u16>,
+    levels_x:     u16,
+    levels_y:     u16,
+    keys_x:       u16,
+    keys_y:       u16,
 }
 
 impl Menu {
-    pub fn new(width: u16, height: u16) -> Self {
-        let out = stdout();
+    pub fn new(screen_width: u16, height: u16) -> Self {
         let selected = 0;
         let max = 3;
-        let levels = [
-            "Level 1 - Snake Pit",
-            "Level 2 - Snake Den",
-            "Level 3 - Lol",
-            "Level 4 - Snake ...",
-            "Level 5 - Snake ... HARDCORE",
-        ];
-        let levels_width = levels
+        let levels = LEVELS
             .iter()
-            .fold(0, |acc, item| acc.max(item.len() as u16))
-            + 10; // Adjust...
+            .enumerate()
+            .map(|(i, level)| format!("Level {} - {}", i + 1, level.name))
+            .collect::<Vec<_>>();
+        let levels_width = MARKER_WIDTH + max_width(levels.iter().map(String::as_str));
         let levels_height = levels.len() as u16;
-        let keys_width = KEYS.iter().fold(0, |acc, s| acc + s.len() as u16);
+        let keys_width = KEYS.iter().map(|text| width(text)).sum();
 
-        let snake_x = (width - SNAKE_WIDTH) / 2;
-        let snake_y = (height - SNAKE_HEIGHT - 2 - levels_height - 2) / 2;
-        let levels_x = (width - levels_width) / 2;
+        let snake_x = center(screen_width, max_width(SNAKE.iter().copied()));
+        let snake_y = center(height, SNAKE_HEIGHT + 2 + levels_height + 4);
+        let levels_x = center(screen_width, levels_width);
         let levels_y = snake_y + SNAKE_HEIGHT + 2;
-        let keys_x = (width - keys_width) / 2;
-        let keys_y = levels_y + levels_height + 1;
+        let keys_x = center(screen_width, keys_width);
+        let keys_y = levels_y + levels_height + 3;
 
         Self {
-            out,
             levels,
             selected,
             max,
+            mode: GameMode::default(),
             snake_x,
             snake_y,
+            snake_y_anim: None,
             levels_x,
             levels_y,
             keys_x,
@@ -74,60 +83,68 @@ impl Menu {
         }
     }
 
-    pub fn show(&mut self, snake_y_anim: Option<u16>) -> MenuAction {
-        if let Some(mut snake_y_anim) = snake_y_anim {
-            loop {
-                if snake_y_anim == self.snake_y {
-                    break;
-                }
-
-                self.snake_frame(&snake_y_anim);
-                self.out.flush().unwrap();
+    /// Makes the snake slide from `snake_y` to its place before the menu
+    /// shows.
+    pub fn slide_from(&mut self, snake_y: u16) {
+        self.snake_y_anim = Some(snake_y).filter(|y| *y != self.snake_y);
+    }
 
-                sleep(Duration::from_millis(1000 / MENU_FPS));
+    /// Returns whether the snake is sliding to its place.
+    pub fn is_sliding(&self) -> bool {
+        self.snake_y_anim.is_some()
+    }
 
-                if let Some(Event::Key(KeyEvent { code, .. })) = poll() {
-                    if code == KeyCode::Esc {
-                        return MenuAction::Quit;
-                    }
-                }
+    /// Draws the sliding snake, or the menu.
+    pub fn render(&self, out: &mut (impl Write + ?Sized), theme: &Theme) {
+        if let Some(snake_y_anim) = self.snake_y_anim {
+            queue!(out, Clear(ClearType::All)).unwrap();
+            self.snake_frame(out, theme, snake_y_anim);
+        } else {
+            self.snake(out, theme);
+            self.levels(out, theme);
+            self.mode(out);
+            self.keys(out);
+        }
+        out.flush().unwrap();
+    }
 
-                self.next_anim_state(&mut snake_y_anim);
-                queue!(self.out, Clear(ClearType::All)).unwrap();
+    /// Moves the sliding snake one frame forward.
+    pub fn update(&mut self) {
+        if let Some(snake_y_anim) = &mut self.snake_y_anim {
+            if *snake_y_anim > self.snake_y {
+                *snake_y_anim -= 1;
+            } else if *snake_y_anim < self.snake_y {
+                *snake_y_anim += 1;
+            }
+            if *snake_y_anim == self.snake_y {
+                self.snake_y_anim = None;
             }
         }
+    }
 
-        loop {
-            self.snake();
-            self.levels();
-            self.keys();
-            self.out.flush().unwrap();
-
-            // TODO: read events instead of poll/wait
-            loop {
-                if let Some(Event::Key(KeyEvent { code, .. })) = poll() {
-                    match code {
-                        KeyCode::Up => {
-                            self.prev();
-                            break;
-                        }
-                        KeyCode::Down => {
-                            self.next();
-                            break;
-                        }
-                        KeyCode::Enter => {
-                            return MenuAction::Level(self.selected);
-                        }
-                        KeyCode::Esc => {
-                            return MenuAction::Quit;
-                        }
-                        _ => {}
-                    }
-                }
-
-                sleep(Duration::from_millis(100));
-            }
+    /// Handles `code`, returning a `MenuAction` if it leaves the menu.
+    ///
+    /// Only `Esc` works while the snake slides.
+    pub fn handle(&mut self, code: KeyCode) -> Option<MenuAction> {
+        if self.is_sliding() {
+            return Some(MenuAction::Quit).filter(|_| code == KeyCode::Esc);
+        }
+
+        match code {
+            KeyCode::Up => self.prev(),
+            KeyCode::Down => self.next(),
+            KeyCode::Left => self.prev_mode(),
+            KeyCode::Right => self.next_mode(),
+            KeyCode::Char('a') => return Some(MenuAction::Achievements),
+            KeyCode::Char('s') => return Some(MenuAction::Stats),
+            KeyCode::Char('h') => return Some(MenuAction::HighScores),
+            KeyCode::Char('o') => return Some(MenuAction::Settings),
+            KeyCode::Enter => return Some(MenuAction::Level(self.selected, self.mode)),
+            KeyCode::Esc => return Some(MenuAction::Quit),
+            _ => {}
         }
+
+        None
     }
 
     fn prev(&mut self) {
@@ -145,55 +162,66 @@ impl Menu {
         }
     }
 
-    fn snake_frame(&mut self, snake_y_anim: &u16) {
+    fn prev_mode(&mut self) {
+        let count = GameMode::ALL.len() as u8;
+        self.mode = GameMode::get((self.mode.id() + count - 1) % count).unwrap_or_default();
+    }
+
+    fn next_mode(&mut self) {
+        let count = GameMode::ALL.len() as u8;
+        self.mode = GameMode::get((self.mode.id() + 1) % count).unwrap_or_default();
+    }
+
+    fn snake_frame(&self, out: &mut (impl Write + ?Sized), theme: &Theme, snake_y_anim: u16) {
         for y in 0..SNAKE_HEIGHT {
             queue!(
-                self.out,
+                out,
                 MoveTo(self.snake_x, snake_y_anim + y),
                 Clear(ClearType::CurrentLine),
-                Print(&SNAKE[y as usize])
+                Print(theme.logo(SNAKE[y as usize]))
             )
             .unwrap();
         }
     }
 
-    fn next_anim_state(&mut self, snake_y_anim: &mut u16) {
-        if *snake_y_anim > self.snake_y {
-            *snake_y_anim -= 1;
-        } else if *snake_y_anim < self.snake_y {
-            *snake_y_anim += 1;
-        }
-    }
-
-    fn snake(&mut self) {
+    fn snake(&self, out: &mut (impl Write + ?Sized), theme: &Theme) {
         for y in 0..SNAKE_HEIGHT {
             queue!(
-                self.out,
+                out,
                 MoveTo(self.snake_x, self.snake_y + y),
                 Clear(ClearType::CurrentLine),
-                Print(&SNAKE[y as usize]),
+                Print(theme.logo(SNAKE[y as usize])),
             )
             .unwrap();
         }
     }
 
-    fn levels(&mut self) {
-        for (i, item) in self.levels.clone().iter().enumerate() {
-            let (before, attr) = if i as u8 == self.selected {
-                ("👅 ", Attribute::Bold)
+    #[allow(clippy::unused_unit)]
+    fn levels(&self, out: &mut (impl Write + ?Sized), theme: &Theme) {
+        for (i, item) in self.levels.iter().enumerate() {
+            let (before, attr, fg, bg) = if i as u8 == self.selected {
+                (
+                    theme.selected,
+                    Attribute::Bold,
+                    PALETTE.highlight_text.color(),
+                    PALETTE.highlight.color(),
+                )
             } else if i as u8 <= self.max {
-                ("🐍 ", Attribute::Reset)
+                (theme.item, Attribute::Reset, Color::Reset, Color::Reset)
             } else {
-                ("   ", Attribute::Dim)
+                ("   ", Attribute::Dim, Color::Reset, Color::Reset)
             };
 
             queue!(
-                self.out,
+                out,
                 MoveTo(self.levels_x, self.levels_y + i as u16),
                 Clear(ClearType::CurrentLine),
                 Print(before),
                 SetAttribute(attr),
+                SetForegroundColor(fg),
+                SetBackgroundColor(bg),
                 Print(item),
+                ResetColor,
                 SetAttribute(Attribute::Reset),
             )
             .unwrap();
@@ -201,29 +229,57 @@ impl Menu {
         ()
     }
 
-    fn keys(&mut self) {
+    fn mode(&self, out: &mut (impl Write + ?Sized)) {
         queue!(
-            self.out,
-            MoveTo(self.keys_x, self.keys_y),
+            out,
+            MoveTo(self.levels_x, self.levels_y + self.levels.len() as u16 + 1),
             Clear(ClearType::CurrentLine),
-            Print(KEYS[0]),
+            Print("Mode: ◀ "),
             SetAttribute(Attribute::Bold),
-            Print(KEYS[1]),
+            Print(self.mode.name()),
             SetAttribute(Attribute::Reset),
-            Print(KEYS[2]),
-            SetAttribute(Attribute::Bold),
-            Print(KEYS[3]),
-            SetAttribute(Attribute::Reset),
-            Print(KEYS[4]),
-            SetAttribute(Attribute::Bold),
-            Print(KEYS[5]),
-            SetAttribute(Attribute::Reset),
-            Print(KEYS[6]),
-            SetAttribute(Attribute::Bold),
-            Print(KEYS[7]),
-            SetAttribute(Attribute::Reset),
-            Print(KEYS[8]),
+            Print(" ▶"),
         )
         .unwrap();
     }
+
+    fn keys(&self, out: &mut (impl Write + ?Sized)) {
+        queue!(
+            out,
+            MoveTo(self.keys_x, self.keys_y),
+            Clear(ClearType::CurrentLine),
+        )
+        .unwrap();
+
+        // Keys are bold, between their descriptions
+        for (i, text) in KEYS.iter().enumerate() {
+            let attr = if i % 2 == 1 {
+                Attribute::Bold
+            } else {
+                Attribute::Reset
+            };
+
+            queue!(out, SetAttribute(attr), Print(text)).unwrap();
+        }
+        queue!(out, SetAttribute(Attribute::Reset)).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        game::MenuScene,
+        testing::{assert_snapshot, run},
+    };
+
+    #[test]
+    fn menu() {
+        let script = [(0, KeyCode::Down), (1, KeyCode::Right)];
+        let backend = run(80, 24, 3, &script, |game| {
+            Box::new(MenuScene::new(game, None))
+        });
+
+        assert_snapshot("menu", &backend);
+    }
 }
@@ -1,7 +1,26 @@
+mod choices;
+mod game_over;
+mod leaderboard;
 mod menu;
+mod name_entry;
+mod pause;
+#[allow(clippy::module_inception)]
 mod screen;
+mod settings;
+mod statistics;
+mod text_input;
+mod trophies;
 mod welcome;
 
+pub use choices::*;
+pub use game_over::*;
+pub use leaderboard::*;
 pub use menu::*;
+pub use name_entry::*;
+pub use pause::*;
 pub use screen::*;
+pub use settings::*;
+pub use statistics::*;
+pub use text_input::*;
+pub use trophies::*;
 pub use welcome::*;
@@ -0,0 +1,84 @@
+use super::{TextInput, TextInputAction};
+use crate::{
+    events::KeyCode,
+    game::NAME_MAX,
+    width::{center, width},
+};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{Clear, ClearType},
+};
+use std::io::Write;
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum NameEntryAction {
+    /// Saves the high score with the name.
+    Save(String),
+    /// Skips the high score.
+    Skip,
+}
+
+/// The `NameEntry` screen asks for the name of a new high score.
+#[derive(Debug)]
+pub struct NameEntry {
+    width:  u16,
+    height: u16,
+    score:  u32,
+    input:  TextInput,
+}
+
+impl NameEntry {
+    /// Creates a new `NameEntry` for the high score `score`, starting from
+    /// `name`.
+    pub fn new(width: u16, height: u16, score: u32, name: &str) -> Self {
+        Self {
+            width,
+            height,
+            score,
+            input: TextInput::new(NAME_MAX).with_text(name),
+        }
+    }
+
+    /// Draws the name entered so far.
+    pub fn render(&self, out: &mut (impl Write + ?Sized)) {
+        let title = format!("New high score: {}!", self.score);
+        let prompt = "Your name: ";
+        // Names may be made of wide characters
+        let input_width = 2 * NAME_MAX as u16 + 1;
+        let x = center(self.width, width(&title).max(width(prompt) + input_width));
+        let y = center(self.height, 5);
+
+        queue!(
+            out,
+            MoveTo(x, y),
+            SetAttribute(Attribute::Bold),
+            Print(&title),
+            SetAttribute(Attribute::Reset),
+            MoveTo(x, y + 4),
+            Print("[↲ save, ESC skip]"),
+        )
+        .unwrap();
+
+        queue!(
+            out,
+            MoveTo(x, y + 2),
+            Clear(ClearType::CurrentLine),
+            Print(prompt),
+            Print(&self.input),
+        )
+        .unwrap();
+        out.flush().unwrap();
+    }
+
+    /// Handles `code`, returning a `NameEntryAction` once the name is
+    /// entered or skipped.
+    pub fn handle(&mut self, code: KeyCode) -> Option<NameEntryAction> {
+        match self.input.handle(code) {
+            TextInputAction::Submit => Some(NameEntryAction::Save(self.input.text())),
+            TextInputAction::Cancel => Some(NameEntryAction::Skip),
+            TextInputAction::Edited | TextInputAction::Ignored => None,
+        }
+    }
+}
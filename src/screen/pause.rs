@@ -0,0 +1,77 @@
+use super::Choices;
+use crate::{
+    events::KeyCode,
+    theme::Theme,
+    width::{center, width},
+};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Print, SetAttribute},
+};
+use std::io::Write;
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PauseAction {
+    Resume,
+    /// Abandons the game for the menu.
+    Menu,
+    Quit,
+}
+
+/// The items of the `Pause` screen, in order.
+const ITEMS: [(PauseAction, &str); 3] = [
+    (PauseAction::Resume, "Resume"),
+    (PauseAction::Menu, "Menu"),
+    (PauseAction::Quit, "Quit"),
+];
+
+const TITLE: &str = "Paused";
+
+/// The `Pause` screen suspends a game.
+#[derive(Debug)]
+pub struct Pause {
+    width:   u16,
+    height:  u16,
+    choices: Choices,
+}
+
+impl Pause {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            choices: Choices::new(ITEMS.len()),
+        }
+    }
+
+    /// Draws the pause menu with `theme`.
+    pub fn render(&self, out: &mut (impl Write + ?Sized), theme: &Theme) {
+        let items = || ITEMS.iter().map(|(_, item)| *item);
+        let x = center(self.width, width(TITLE).max(Choices::width(items())));
+        let y = center(self.height, 2 + ITEMS.len() as u16);
+
+        queue!(
+            out,
+            MoveTo(x, y),
+            SetAttribute(Attribute::Bold),
+            Print(TITLE),
+            SetAttribute(Attribute::Reset),
+        )
+        .unwrap();
+        self.choices.draw(out, theme, x, y + 2, items());
+        out.flush().unwrap();
+    }
+
+    /// Handles `code`, returning a `PauseAction` once one is chosen.
+    ///
+    /// `Esc`, `Space` and `P` resume.
+    pub fn handle(&mut self, code: KeyCode) -> Option<PauseAction> {
+        match code {
+            _ if self.choices.handle(code) => None,
+            KeyCode::Enter => Some(ITEMS[self.choices.selected()].0),
+            KeyCode::Esc | KeyCode::Char(' ') | KeyCode::Char('p') => Some(PauseAction::Resume),
+            _ => None,
+        }
+    }
+}
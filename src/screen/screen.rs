@@ -1,53 +1,43 @@
-use super::{Menu, MenuAction, Welcome, WelcomeAction};
 use crate::{
-    consts::{ALTERNATE_SCREEN, RAW_MODE, TITLE},
+    backend::{Backend, CrosstermBackend},
+    color::{self, ColorDepth},
+    consts::{ALTERNATE_SCREEN, TITLE},
     physics::Point,
 };
 use crossterm::{
     cursor::{Hide, Show},
     execute,
     queue,
-    terminal::{
-        disable_raw_mode,
-        enable_raw_mode,
-        size,
-        Clear,
-        ClearType,
-        EnterAlternateScreen,
-        LeaveAlternateScreen,
-        SetTitle,
-    },
+    terminal::{Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, SetTitle},
 };
-use std::io::{stdout, Stdout, Write};
+use std::io::Write;
 
 #[derive(Debug)]
 pub struct Screen {
-    out:             Stdout,
-    entered:         bool,
-    width:           u16,
-    height:          u16,
-    welcome:         Welcome,
-    menu:            Menu,
-    menu_shown_once: bool,
+    out:     Box<dyn Backend>,
+    entered: bool,
+    width:   u16,
+    height:  u16,
 }
 
 impl Screen {
+    /// Creates a `Screen` on the real terminal.
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
+        color::set_depth(ColorDepth::detect());
+        Self::with_backend(Box::new(CrosstermBackend::new()))
+    }
+
+    /// Creates a `Screen` rendering to `out`.
+    pub fn with_backend(out: Box<dyn Backend>) -> Self {
         let entered = false;
-        let out = stdout();
-        let (width, height) = size().expect("Cannot get terminal size");
-        let welcome = Welcome::new(width, height);
-        let menu = Menu::new(width, height);
-        let menu_shown_once = false;
+        let (width, height) = out.size().expect("Cannot get terminal size");
 
         Self {
             out,
             entered,
             width,
             height,
-            welcome,
-            menu,
-            menu_shown_once,
         }
     }
 
@@ -63,32 +53,13 @@ impl Screen {
         (self.width as f64, self.height as f64).into()
     }
 
-    pub fn clear(&mut self) {
-        execute!(self.out, Clear(ClearType::All)).unwrap();
-    }
-
-    pub fn welcome(&mut self) -> WelcomeAction {
-        self.clear();
-        let action = self.welcome.show();
-        self.clear();
-
-        action
+    /// Returns the `Backend` to render to.
+    pub fn out(&mut self) -> &mut dyn Backend {
+        &mut *self.out
     }
 
-    pub fn menu(&mut self) -> MenuAction {
-        let snake_y_anim = if !self.menu_shown_once && self.welcome.is_snake_blinking() {
-            Some(self.welcome.snake_y())
-        } else {
-            None
-        };
-
-        self.clear();
-        let action = self.menu.show(snake_y_anim);
-        self.clear();
-
-        self.menu_shown_once = true;
-
-        action
+    pub fn clear(&mut self) {
+        execute!(self.out, Clear(ClearType::All)).unwrap();
     }
 
     pub fn enter(&mut self) {
@@ -96,9 +67,7 @@ impl Screen {
             return;
         }
 
-        if RAW_MODE {
-            enable_raw_mode().expect("Cannot enable raw mode");
-        }
+        self.out.enter().expect("Cannot enable raw mode");
 
         queue!(self.out, Hide).expect("Cannot hide cursor");
 
@@ -126,9 +95,7 @@ impl Screen {
         queue!(self.out, SetTitle("")).expect("Cannot restore terminal title");
         self.out.flush().expect("Cannot flush");
 
-        if RAW_MODE {
-            disable_raw_mode().expect("Cannot disable raw mode");
-        }
+        self.out.leave().expect("Cannot disable raw mode");
 
         self.entered = false;
     }
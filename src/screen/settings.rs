@@ -0,0 +1,127 @@
+use super::Choices;
+use crate::{
+    cli::Options,
+    events::KeyCode,
+    width::{center, width},
+};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Print, SetAttribute},
+};
+use std::io::Write;
+
+const TITLE: &str = "Settings";
+const KEYS: &str = "[↑↓ select, ←→ ↲ change, ESC back]";
+
+/// The number of settings.
+const SETTINGS: usize = 4;
+
+/// The `Settings` screen changes the `Options` of the next games.
+#[derive(Debug)]
+pub struct Settings {
+    width:   u16,
+    height:  u16,
+    choices: Choices,
+}
+
+impl Settings {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            choices: Choices::new(SETTINGS),
+        }
+    }
+
+    /// Draws `options`.
+    pub fn render(&self, out: &mut (impl Write + ?Sized), options: &Options) {
+        let x = center(self.width, width(KEYS));
+        let y = center(self.height, 2 + SETTINGS as u16 + 2);
+
+        queue!(
+            out,
+            MoveTo(x, y),
+            SetAttribute(Attribute::Bold),
+            Print(TITLE),
+            SetAttribute(Attribute::Reset),
+            MoveTo(x, y + 2 + SETTINGS as u16 + 1),
+            Print(KEYS),
+        )
+        .unwrap();
+
+        let items = items(options);
+        self.choices.draw(
+            out,
+            &options.theme,
+            x,
+            y + 2,
+            items.iter().map(String::as_str),
+        );
+        out.flush().unwrap();
+    }
+
+    /// Handles `code`, changing `options`.
+    ///
+    /// Returns whether it leaves the settings, on `Esc`.
+    pub fn handle(&mut self, code: KeyCode, options: &mut Options) -> bool {
+        match code {
+            _ if self.choices.handle(code) => {}
+            KeyCode::Left | KeyCode::Right | KeyCode::Enter =>
+                change(options, self.choices.selected()),
+            KeyCode::Esc => return true,
+            _ => {}
+        }
+
+        false
+    }
+}
+
+/// Returns the items of the settings, with their values.
+fn items(options: &Options) -> [String; SETTINGS] {
+    let on_off = |on| if on { "on" } else { "off" };
+
+    [
+        format!("Theme: {}", options.theme.name),
+        format!("Players: {}", if options.two_players { 2 } else { 1 }),
+        format!("Diagonals: {}", on_off(options.diagonals)),
+        format!("Relative controls: {}", on_off(options.relative[0])),
+    ]
+}
+
+/// Changes the setting at `index` to its next value.
+fn change(options: &mut Options, index: usize) {
+    match index {
+        0 => options.theme = options.theme.next(),
+        1 => options.two_players = !options.two_players,
+        2 => options.diagonals = !options.diagonals,
+        _ => options.relative[0] = !options.relative[0],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backend::MemoryBackend,
+        testing::text,
+        theme::{ASCII, BLOCKS, EMOJI},
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn cycle_themes() {
+        let mut settings = Settings::new(60, 12);
+        let mut options = Options::default();
+        let mut backend = MemoryBackend::new(60, 12);
+
+        for theme in [ASCII, BLOCKS, EMOJI].iter() {
+            assert!(!settings.handle(KeyCode::Right, &mut options));
+            assert_eq!(options.theme, *theme);
+
+            settings.render(&mut backend, &options);
+            let marked = format!("{}Theme: {}", theme.selected, theme.name);
+            assert!(text(&backend).contains(&marked), "{}", text(&backend));
+        }
+    }
+}
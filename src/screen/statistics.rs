@@ -0,0 +1,57 @@
+use crate::{
+    game::Stats,
+    width::{center, max_width, width},
+};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Print, SetAttribute},
+};
+use std::io::Write;
+
+/// The `Statistics` screen shows the lifetime stats.
+#[derive(Debug)]
+pub struct Statistics {
+    width:  u16,
+    height: u16,
+}
+
+impl Statistics {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self { width, height }
+    }
+
+    /// Draws `stats`, until a key is pressed.
+    pub fn render(&self, out: &mut (impl Write + ?Sized), stats: &Stats) {
+        let rows = stats.rows();
+        let label_width = max_width(rows.iter().map(|(label, _)| &label[..]));
+        let value_width = max_width(rows.iter().map(|(_, value)| &value[..]));
+        let x = center(self.width, label_width + 2 + value_width);
+        let y = center(self.height, rows.len() as u16 + 2);
+
+        for (i, (label, value)) in rows.iter().enumerate() {
+            // Padded by display width, as `format!` pads by characters
+            let label_padding = " ".repeat(usize::from(label_width - width(label) + 2));
+            let value_padding = " ".repeat(usize::from(value_width - width(value)));
+
+            queue!(
+                out,
+                MoveTo(x, y + i as u16),
+                Print(label),
+                Print(label_padding),
+                SetAttribute(Attribute::Bold),
+                Print(value_padding),
+                Print(value),
+                SetAttribute(Attribute::Reset),
+            )
+            .unwrap();
+        }
+        queue!(
+            out,
+            MoveTo(x, y + rows.len() as u16 + 1),
+            Print("[Press any key to go back]"),
+        )
+        .unwrap();
+        out.flush().unwrap();
+    }
+}
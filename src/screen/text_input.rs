@@ -0,0 +1,159 @@
+use crate::{events::KeyCode, width::width};
+use crossterm::style::{Attribute, SetAttribute};
+use std::fmt::{Display, Error, Formatter};
+
+/// What a key did to a `TextInput`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum TextInputAction {
+    /// The text or the cursor changed.
+    Edited,
+    /// The text was validated with `Enter`.
+    Submit,
+    /// The input was cancelled with `ESC`.
+    Cancel,
+    /// The key does nothing here.
+    Ignored,
+}
+
+/// A single line `TextInput` of at most `max` characters, with a cursor.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct TextInput {
+    chars:  Vec<char>,
+    cursor: usize,
+    max:    usize,
+}
+
+impl TextInput {
+    /// Creates a new empty `TextInput` of at most `max` characters.
+    pub fn new(max: usize) -> Self {
+        Self {
+            chars: vec![],
+            cursor: 0,
+            max,
+        }
+    }
+
+    /// Sets the text, truncated to `max` characters, with the cursor at its
+    /// end.
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.chars = text.chars().take(self.max).collect();
+        self.cursor = self.chars.len();
+        self
+    }
+
+    /// Returns the text.
+    pub fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    /// Returns the position of the cursor, in characters.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Handles a key.
+    pub fn handle(&mut self, code: KeyCode) -> TextInputAction {
+        match code {
+            KeyCode::Char(c) if !c.is_control() && self.chars.len() < self.max => {
+                self.chars.insert(self.cursor, c);
+                self.cursor += 1;
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.chars.remove(self.cursor);
+            }
+            KeyCode::Delete if self.cursor < self.chars.len() => {
+                self.chars.remove(self.cursor);
+            }
+            KeyCode::Left if self.cursor > 0 => self.cursor -= 1,
+            KeyCode::Right if self.cursor < self.chars.len() => self.cursor += 1,
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.chars.len(),
+            KeyCode::Enter => return TextInputAction::Submit,
+            KeyCode::Esc => return TextInputAction::Cancel,
+            _ => return TextInputAction::Ignored,
+        }
+
+        TextInputAction::Edited
+    }
+}
+
+impl Display for TextInput {
+    /// Draws the text with the cursor in reverse video, padded to `max`
+    /// columns.
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        for (i, c) in self.chars.iter().enumerate() {
+            if i == self.cursor {
+                write!(
+                    f,
+                    "{}{}{}",
+                    SetAttribute(Attribute::Reverse),
+                    c,
+                    SetAttribute(Attribute::NoReverse)
+                )?;
+            } else {
+                write!(f, "{}", c)?;
+            }
+        }
+
+        let mut shown = usize::from(width(&self.text()));
+        if self.cursor == self.chars.len() {
+            write!(
+                f,
+                "{} {}",
+                SetAttribute(Attribute::Reverse),
+                SetAttribute(Attribute::NoReverse)
+            )?;
+            shown += 1;
+        }
+
+        write!(f, "{}", "_".repeat(self.max.saturating_sub(shown)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn type_keys(input: &mut TextInput, codes: &[KeyCode]) {
+        for code in codes {
+            input.handle(*code);
+        }
+    }
+
+    #[test]
+    fn edit() {
+        let mut input = TextInput::new(10);
+        type_keys(&mut input, &[
+            KeyCode::Char('s'),
+            KeyCode::Char('ñ'),
+            KeyCode::Char('k'),
+            KeyCode::Left,
+            KeyCode::Left,
+            KeyCode::Backspace,
+            KeyCode::Char('é'),
+            KeyCode::End,
+            KeyCode::Char('🐍'),
+            KeyCode::Home,
+            KeyCode::Delete,
+        ]);
+
+        assert_eq!(input.text(), "ñk🐍");
+        assert_eq!(input.cursor(), 0);
+        assert_eq!(input.handle(KeyCode::Backspace), TextInputAction::Ignored);
+        assert_eq!(input.handle(KeyCode::Enter), TextInputAction::Submit);
+        assert_eq!(input.handle(KeyCode::Esc), TextInputAction::Cancel);
+    }
+
+    #[test]
+    fn max_length() {
+        let mut input = TextInput::new(3).with_text("abcdef");
+        assert_eq!(input.text(), "abc");
+        assert_eq!(input.cursor(), 3);
+
+        assert_eq!(input.handle(KeyCode::Char('d')), TextInputAction::Ignored);
+        assert_eq!(input.handle(KeyCode::Char('\t')), TextInputAction::Ignored);
+        assert_eq!(input.text(), "abc");
+    }
+}
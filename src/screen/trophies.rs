@@ -0,0 +1,78 @@
+use crate::{
+    game::{Achievement, Achievements},
+    theme::{Theme, MARKER_WIDTH},
+    width::{center, max_width},
+};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Print, SetAttribute},
+};
+use std::io::Write;
+
+/// The `Trophies` screen lists the locked and unlocked achievements.
+#[derive(Debug)]
+pub struct Trophies {
+    width:  u16,
+    height: u16,
+}
+
+impl Trophies {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self { width, height }
+    }
+
+    /// Draws `achievements` with `theme`, until a key is pressed.
+    pub fn render(
+        &self,
+        out: &mut (impl Write + ?Sized),
+        achievements: &Achievements,
+        theme: &Theme,
+    ) {
+        let lines = Achievement::ALL
+            .iter()
+            .map(|achievement| line(achievements, *achievement))
+            .collect::<Vec<_>>();
+        let lines_width = MARKER_WIDTH + max_width(lines.iter().map(|(_, text)| &text[..]));
+        let x = center(self.width, lines_width);
+        let y = center(self.height, lines.len() as u16 + 2);
+
+        for (i, (unlocked, text)) in lines.iter().enumerate() {
+            let (before, attr) = if *unlocked {
+                (theme.unlocked, Attribute::Bold)
+            } else {
+                (theme.locked, Attribute::Dim)
+            };
+
+            queue!(
+                out,
+                MoveTo(x, y + i as u16),
+                Print(before),
+                SetAttribute(attr),
+                Print(text),
+                SetAttribute(Attribute::Reset),
+            )
+            .unwrap();
+        }
+        queue!(
+            out,
+            MoveTo(x, y + lines.len() as u16 + 1),
+            Print("[Press any key to go back]"),
+        )
+        .unwrap();
+        out.flush().unwrap();
+    }
+}
+
+/// Returns whether `achievement` is unlocked, with its line.
+fn line(achievements: &Achievements, achievement: Achievement) -> (bool, String) {
+    let text = format!(
+        "{} - {} ({}/{})",
+        achievement.name(),
+        achievement.description(),
+        achievements.progress(achievement),
+        achievement.goal()
+    );
+
+    (achievements.is_unlocked(achievement), text)
+}
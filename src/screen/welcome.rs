@@ -1,19 +1,23 @@
 use crate::{
+    ai::Difficulty,
     consts::{
         CONTINUE,
         CONTINUE_DELAY,
         CONTINUE_HEIGHT,
-        CONTINUE_WIDTH,
+        DEMO_DELAY,
+        DEMO_FRAMES_PER_UPDATE,
+        FPS,
         SNAKE,
         SNAKE_BLINK_TIME,
         SNAKE_HEIGHT,
-        SNAKE_WIDTH,
         WELCOME,
-        WELCOME_FPS,
         WELCOME_HEIGHT,
-        WELCOME_WIDTH,
     },
-    events::{poll, Event, KeyCode, KeyEvent},
+    events::KeyCode,
+    game::{Config, Level, World},
+    physics::Point,
+    theme::{Draw, Theme},
+    width::{self, center, max_width},
 };
 use crossterm::{
     cursor::MoveTo,
@@ -21,11 +25,7 @@ use crossterm::{
     style::Print,
     terminal::{Clear, ClearType},
 };
-use std::{
-    io::{stdout, Stdout, Write},
-    thread::sleep,
-    time::Duration,
-};
+use std::{io::Write, time::Duration};
 
 #[derive(Debug)]
 pub enum WelcomeAction {
@@ -37,11 +37,12 @@ pub enum WelcomeAction {
 enum State {
     SlidingWelcome(u16),
     BlinkingSnake(u64),
+    /// An autoplaying game, with the frames since it started.
+    Demo(Box<World>, u64),
 }
 
 #[derive(Debug)]
 pub struct Welcome {
-    out:        Stdout,
     state:      State,
     width:      u16,
     height:     u16,
@@ -55,18 +56,17 @@ pub struct Welcome {
 
 impl Welcome {
     pub fn new(width: u16, height: u16) -> Self {
-        let out = stdout();
         let state = State::SlidingWelcome(0);
 
-        let welcome_x = (width - WELCOME_WIDTH) / 2;
-        let welcome_y = (height - WELCOME_HEIGHT - 1 - SNAKE_HEIGHT - 1 - CONTINUE_HEIGHT) / 2;
-        let snake_x = (width - SNAKE_WIDTH) / 2;
+        let (_, size_y) = Self::size();
+        let welcome_x = center(width, max_width(WELCOME.iter().copied()));
+        let welcome_y = center(height, size_y);
+        let snake_x = center(width, max_width(SNAKE.iter().copied()));
         let snake_y = welcome_y + 1 + WELCOME_HEIGHT;
-        let continue_x = (width - CONTINUE_WIDTH) / 2;
+        let continue_x = center(width, max_width(CONTINUE.iter().copied()));
         let continue_y = snake_y + 1 + SNAKE_HEIGHT;
 
         Self {
-            out,
             state,
             width,
             height,
@@ -81,11 +81,12 @@ impl Welcome {
 
     pub fn size() -> (u16, u16) {
         (
-            WELCOME_WIDTH.max(SNAKE_WIDTH).max(CONTINUE_WIDTH),
+            max_width(WELCOME.iter().chain(&SNAKE).chain(&CONTINUE).copied()),
             WELCOME_HEIGHT + 1 + SNAKE_HEIGHT + 1 + CONTINUE_HEIGHT,
         )
     }
 
+    #[allow(clippy::match_like_matches_macro)]
     pub fn is_snake_blinking(&self) -> bool {
         if let State::BlinkingSnake(_) = self.state {
             true
@@ -98,67 +99,113 @@ impl Welcome {
         self.snake_y
     }
 
-    pub fn show(&mut self) -> WelcomeAction {
-        loop {
-            self.welcome_frame();
-            self.snake_frame();
-            self.continue_frame();
-            self.out.flush().unwrap();
+    /// Draws what changed since the previous frame.
+    pub fn render(&self, out: &mut (impl Write + ?Sized), theme: &Theme) {
+        self.welcome_frame(out);
+        self.snake_frame(out, theme);
+        self.continue_frame(out);
+        self.demo_frame(out, theme);
+        out.flush().unwrap();
+    }
 
-            if let Some(Event::Key(KeyEvent { code, .. })) = poll() {
-                if code == KeyCode::Esc {
-                    return WelcomeAction::Quit;
-                } else {
-                    return WelcomeAction::Continue;
-                }
-            }
+    /// Handles `code`, returning a `WelcomeAction` unless it stopped the
+    /// demo.
+    pub fn handle(
+        &mut self,
+        out: &mut (impl Write + ?Sized),
+        theme: &Theme,
+        code: KeyCode,
+    ) -> Option<WelcomeAction> {
+        if let State::Demo(..) = self.state {
+            self.stop_demo(out, theme);
+            None
+        } else if code == KeyCode::Esc {
+            Some(WelcomeAction::Quit)
+        } else {
+            Some(WelcomeAction::Continue)
+        }
+    }
+
+    fn start_demo(&mut self) {
+        let bounds = Point::new(f64::from(width::cells(self.width)), f64::from(self.height));
+        let config = Config::new(bounds, Duration::from_millis(1000 / FPS));
+        let mut world = World::new(config, rand::random(), Level::get(0));
+        world.autoplay(Difficulty::Hard);
+
+        self.state = State::Demo(Box::new(world), 0);
+    }
+
+    fn stop_demo(&mut self, out: &mut (impl Write + ?Sized), theme: &Theme) {
+        queue!(out, Clear(ClearType::All)).unwrap();
 
-            sleep(Duration::from_millis(1000 / WELCOME_FPS));
-            self.next_state();
+        for y in 0..WELCOME_HEIGHT {
+            queue!(
+                out,
+                MoveTo(self.welcome_x, self.welcome_y + y),
+                Print(&WELCOME[usize::from(y)]),
+            )
+            .unwrap();
         }
+        // Shown now, as the blinking goes on from the next frame
+        self.snake(out, theme);
+
+        self.state = State::BlinkingSnake(0);
     }
 
-    fn welcome_frame(&mut self) {
+    fn welcome_frame(&self, out: &mut (impl Write + ?Sized)) {
         if let State::SlidingWelcome(x) = self.state {
-            let i = WELCOME_WIDTH.min(x);
-
             for y in 0..WELCOME_HEIGHT {
                 queue!(
-                    self.out,
+                    out,
                     MoveTo(self.width - x, self.welcome_y + y),
                     Clear(ClearType::CurrentLine),
-                    Print(&WELCOME[usize::from(y)][0..usize::from(i)]),
+                    Print(width::truncate(WELCOME[usize::from(y)], x)),
                 )
                 .unwrap();
             }
         }
     }
 
-    fn snake_frame(&mut self) {
+    fn snake_frame(&self, out: &mut (impl Write + ?Sized), theme: &Theme) {
         if let State::BlinkingSnake(x) = self.state {
             let cycle = x % (SNAKE_BLINK_TIME * 2);
-            let show = cycle == 0;
-            let hide = cycle == SNAKE_BLINK_TIME;
 
-            if show || hide {
+            if cycle == 0 {
+                self.snake(out, theme);
+            } else if cycle == SNAKE_BLINK_TIME {
                 for y in 0..SNAKE_HEIGHT {
-                    queue!(self.out, MoveTo(self.snake_x, self.snake_y + y)).unwrap();
-                    if show {
-                        queue!(self.out, Print(&SNAKE[y as usize])).unwrap();
-                    } else {
-                        queue!(self.out, Clear(ClearType::CurrentLine)).unwrap();
-                    }
+                    queue!(
+                        out,
+                        MoveTo(self.snake_x, self.snake_y + y),
+                        Clear(ClearType::CurrentLine)
+                    )
+                    .unwrap();
                 }
             }
         }
     }
 
-    fn continue_frame(&mut self) {
+    fn snake(&self, out: &mut (impl Write + ?Sized), theme: &Theme) {
+        for y in 0..SNAKE_HEIGHT {
+            let line = theme.logo(SNAKE[y as usize]);
+            queue!(out, MoveTo(self.snake_x, self.snake_y + y), Print(line)).unwrap();
+        }
+    }
+
+    fn demo_frame(&self, out: &mut (impl Write + ?Sized), theme: &Theme) {
+        if let State::Demo(world, x) = &self.state {
+            if *x % DEMO_FRAMES_PER_UPDATE == 0 {
+                queue!(out, Clear(ClearType::All), Print(world.themed(theme))).unwrap();
+            }
+        }
+    }
+
+    fn continue_frame(&self, out: &mut (impl Write + ?Sized)) {
         if let State::BlinkingSnake(x) = self.state {
             if x == CONTINUE_DELAY {
                 for y in 0..CONTINUE_HEIGHT {
                     queue!(
-                        self.out,
+                        out,
                         MoveTo(self.continue_x, self.continue_y + y),
                         Print(&CONTINUE[y as usize])
                     )
@@ -168,7 +215,8 @@ impl Welcome {
         }
     }
 
-    fn next_state(&mut self) {
+    /// Moves the animation one frame forward.
+    pub fn update(&mut self) {
         match self.state {
             State::SlidingWelcome(x) =>
                 if x > self.width - self.welcome_x {
@@ -180,8 +228,45 @@ impl Welcome {
             State::BlinkingSnake(x) => {
                 // qdf
 
-                self.state = State::BlinkingSnake(x + 1)
+                if x >= DEMO_DELAY {
+                    self.start_demo();
+                } else {
+                    self.state = State::BlinkingSnake(x + 1);
+                }
+            }
+            State::Demo(ref mut world, ref mut x) => {
+                *x += 1;
+
+                if *x % DEMO_FRAMES_PER_UPDATE == 0 {
+                    world.update();
+
+                    if world.is_over() {
+                        self.start_demo();
+                    }
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game::WelcomeScene,
+        testing::{assert_snapshot, run},
+    };
+
+    #[test]
+    fn sliding() {
+        let backend = run(80, 24, 30, &[], |game| Box::new(WelcomeScene::new(game)));
+
+        assert_snapshot("welcome_sliding", &backend);
+    }
+
+    #[test]
+    fn continue_() {
+        let backend = run(80, 24, 280, &[], |game| Box::new(WelcomeScene::new(game)));
+
+        assert_snapshot("welcome_continue", &backend);
+    }
+}
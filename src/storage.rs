@@ -0,0 +1,27 @@
+//! Where the game keeps its files
+
+use std::{
+    env,
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::PathBuf,
+};
+
+/// Returns the directory of the game's files, creating it if needed.
+///
+/// This is `$SNAKE_DATA_DIR`, or `snake` in `$XDG_DATA_HOME` or
+/// `$HOME/.local/share`.
+pub fn dir() -> Result<PathBuf> {
+    let dir = if let Some(dir) = env::var_os("SNAKE_DATA_DIR") {
+        PathBuf::from(dir)
+    } else if let Some(dir) = env::var_os("XDG_DATA_HOME") {
+        PathBuf::from(dir).join("snake")
+    } else if let Some(home) = env::var_os("HOME") {
+        PathBuf::from(home).join(".local/share/snake")
+    } else {
+        return Err(Error::new(ErrorKind::NotFound, "No data directory"));
+    };
+
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
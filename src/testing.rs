@@ -0,0 +1,171 @@
+//! Snapshot tests of the rendered frames.
+//!
+//! `Scenes` are run on a `MemoryBackend` and the resulting grid is compared,
+//! as text, with a file of the `snapshots` directory. Run the tests with
+//! `UPDATE_SNAPSHOTS=1` to accept new or changed snapshots.
+
+use crate::{
+    backend::{Backend, MemoryBackend},
+    cli::Options,
+    events::KeyCode,
+    game::{Game, Scene, Scenes},
+    screen::Screen,
+};
+use pretty_assertions::assert_eq;
+use std::{
+    cell::RefCell,
+    env,
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    rc::Rc,
+};
+
+/// A `MemoryBackend` shared between the `Screen` of a `Game` and a test.
+#[derive(Clone, Debug)]
+pub struct SharedBackend(Rc<RefCell<MemoryBackend>>);
+
+impl Write for SharedBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.borrow_mut().flush()
+    }
+}
+
+impl Backend for SharedBackend {
+    fn size(&self) -> io::Result<(u16, u16)> {
+        self.0.borrow().size()
+    }
+}
+
+/// Returns a `Game` with nothing saved, drawn on a blank `width` × `height`
+/// `MemoryBackend`, and the backend.
+pub fn game(width: u16, height: u16) -> (Game, SharedBackend) {
+    let backend = SharedBackend(Rc::new(RefCell::new(MemoryBackend::new(width, height))));
+    let screen = Screen::with_backend(Box::new(backend.clone()));
+
+    (Game::with_screen(screen, Options::default()), backend)
+}
+
+/// Runs `ticks` frames of `Scenes` starting with the `Scene` that `first`
+/// creates, on a `game` of `width` × `height`.
+///
+/// The keys of `script` are pressed on their frame, as if before the next
+/// one. Stops early when no `Scene` is left.
+pub fn run(
+    width: u16,
+    height: u16,
+    ticks: u32,
+    script: &[(u32, KeyCode)],
+    first: impl FnOnce(&Game) -> Box<dyn Scene>,
+) -> MemoryBackend {
+    let (mut game, backend) = game(width, height);
+    let mut scenes = Scenes::new(first(&game));
+
+    for i in 0..ticks {
+        if scenes.is_empty() {
+            break;
+        }
+
+        let keys = script
+            .iter()
+            .filter(|(at, _)| *at == i)
+            .map(|(_, code)| *code);
+        scenes.frame(&mut game, keys);
+    }
+
+    let backend = backend.0.borrow().clone();
+    backend
+}
+
+/// Returns the text of `backend`, without trailing spaces.
+pub fn text(backend: &MemoryBackend) -> String {
+    backend
+        .lines()
+        .iter()
+        .map(|line| format!("{}\n", line.trim_end()))
+        .collect()
+}
+
+/// Asserts that `backend` matches the snapshot `name`.
+pub fn assert_snapshot(name: &str, backend: &MemoryBackend) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{}.txt", name));
+    let actual = text(backend);
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "Missing snapshot {}, run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+
+    // Line by line, for a readable diff
+    assert_eq!(
+        expected.lines().collect::<Vec<_>>(),
+        actual.lines().collect::<Vec<_>>(),
+        "Snapshot {} changed, run with UPDATE_SNAPSHOTS=1 to accept it",
+        name
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Transition;
+    use crossterm::{queue, style::Print};
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+
+    /// Prints the keys pressed on each frame, `.` without any.
+    #[derive(Debug, Default)]
+    struct Echo {
+        pressed: bool,
+    }
+
+    impl Scene for Echo {
+        fn period(&self) -> Option<Duration> {
+            Some(Duration::from_millis(100))
+        }
+
+        fn handle(&mut self, game: &mut Game, code: KeyCode) -> Option<Transition> {
+            let c = match code {
+                KeyCode::Char(c) => c,
+                _ => '?',
+            };
+            queue!(game.screen().out(), Print(c)).unwrap();
+            self.pressed = true;
+
+            None
+        }
+
+        fn update(&mut self, game: &mut Game) -> Option<Transition> {
+            if !self.pressed {
+                queue!(game.screen().out(), Print('.')).unwrap();
+            }
+            self.pressed = false;
+
+            None
+        }
+
+        fn render(&mut self, _: &mut Game) {}
+    }
+
+    #[test]
+    fn script() {
+        let script = [(1, KeyCode::Char('a')), (1, KeyCode::Char('b'))];
+        let backend = run(8, 1, 3, &script, |_| Box::new(Echo::default()));
+
+        assert_eq!(text(&backend), ".ab.\n");
+    }
+}
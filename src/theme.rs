@@ -0,0 +1,232 @@
+//! Themes, the glyphs everything is drawn with
+//!
+//! Cells are two columns wide, so are their glyphs.
+
+use std::fmt::{Display, Error, Formatter};
+
+/// The glyphs of a kind of snake.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SnakeGlyphs {
+    pub head: &'static str,
+    pub body: &'static str,
+    pub tail: &'static str,
+}
+
+/// A `Theme` defines the glyphs of the game.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Theme {
+    pub name:     &'static str,
+    /// The snakes of the players.
+    pub player:   SnakeGlyphs,
+    /// The snakes of the bots.
+    pub opponent: SnakeGlyphs,
+    /// The personal best racing along.
+    pub ghost:    &'static str,
+    pub food:     &'static str,
+    pub wall:     &'static str,
+    pub rock:     &'static str,
+    pub portal:   &'static str,
+    /// The marker of the selected item of menus, three columns wide as all
+    /// markers.
+    pub selected: &'static str,
+    /// The marker of the other items of menus.
+    pub item:     &'static str,
+    /// The marker of unlocked achievements.
+    pub unlocked: &'static str,
+    /// The marker of locked achievements.
+    pub locked:   &'static str,
+}
+
+/// Colorful, where emoji render well.
+pub const EMOJI: Theme = Theme {
+    name:     "emoji",
+    player:   SnakeGlyphs {
+        head: "🐍",
+        body: "🐍",
+        tail: "🐍",
+    },
+    opponent: SnakeGlyphs {
+        head: "🦖",
+        body: "🦖",
+        tail: "🦖",
+    },
+    ghost:    "░░",
+    food:     "🦀",
+    wall:     "🧱",
+    rock:     "🪨",
+    portal:   "🌀",
+    selected: "👅 ",
+    item:     "🐍 ",
+    unlocked: "🏆 ",
+    locked:   "🔒 ",
+};
+
+/// Pure ASCII, for any terminal.
+pub const ASCII: Theme = Theme {
+    name:     "ascii",
+    player:   SnakeGlyphs {
+        head: "@@",
+        body: "oo",
+        tail: "..",
+    },
+    opponent: SnakeGlyphs {
+        head: "XX",
+        body: "xx",
+        tail: "..",
+    },
+    ghost:    "::",
+    food:     "<>",
+    wall:     "##",
+    rock:     "()",
+    portal:   "{}",
+    selected: "-> ",
+    item:     " - ",
+    unlocked: "[x]",
+    locked:   "[ ]",
+};
+
+/// Unicode blocks, for terminals without emoji.
+pub const BLOCKS: Theme = Theme {
+    name:     "blocks",
+    player:   SnakeGlyphs {
+        head: "██",
+        body: "▓▓",
+        tail: "▒▒",
+    },
+    opponent: SnakeGlyphs {
+        head: "▐▌",
+        body: "▞▚",
+        tail: "░░",
+    },
+    ghost:    "··",
+    food:     "◆◆",
+    wall:     "▀▄",
+    rock:     "▟▙",
+    portal:   "◖◗",
+    selected: "▶  ",
+    item:     "▪  ",
+    unlocked: "■  ",
+    locked:   "□  ",
+};
+
+/// The built-in themes.
+pub const THEMES: [Theme; 3] = [EMOJI, ASCII, BLOCKS];
+
+/// The width of the markers of all themes, in columns.
+pub const MARKER_WIDTH: u16 = 3;
+
+/// Something drawn with the glyphs of a `Theme`.
+pub trait Draw {
+    /// Draws on `f` with the glyphs of `theme`.
+    fn draw(&self, f: &mut Formatter, theme: &Theme) -> Result<(), Error>;
+
+    /// Returns a `Display` drawing with `theme`.
+    fn themed<'a>(&'a self, theme: &'a Theme) -> Themed<'a, Self> {
+        Themed { item: self, theme }
+    }
+}
+
+/// `Themed` displays an `item` drawn with a `Theme`.
+#[derive(Debug)]
+pub struct Themed<'a, T: ?Sized> {
+    item:  &'a T,
+    theme: &'a Theme,
+}
+
+impl<T: ?Sized> Clone for Themed<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for Themed<'_, T> {}
+
+impl<T: Draw + ?Sized> Display for Themed<'_, T> {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        self.item.draw(f, self.theme)
+    }
+}
+
+impl Theme {
+    /// Returns the built-in `Theme` called `name`, if any.
+    pub fn get(name: &str) -> Option<Self> {
+        THEMES.iter().find(|theme| theme.name == name).copied()
+    }
+
+    /// Returns the built-in `Theme` after this one, wrapping around.
+    pub fn next(&self) -> Self {
+        let index = THEMES.iter().position(|theme| theme == self).unwrap_or(0);
+
+        THEMES[(index + 1) % THEMES.len()]
+    }
+
+    /// Returns `line` of the `SNAKE` logo drawn with this `Theme`.
+    pub fn logo(&self, line: &str) -> String {
+        line.replace('🐍', self.player.body)
+            .replace('👅', self.player.head)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        EMOJI
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::width::{width, CELL_WIDTH};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn get() {
+        assert_eq!(Theme::get("ascii"), Some(ASCII));
+        assert_eq!(Theme::get("neon"), None);
+    }
+
+    #[test]
+    fn widths() {
+        for theme in THEMES.iter() {
+            let glyphs = [
+                theme.player.head,
+                theme.player.body,
+                theme.player.tail,
+                theme.opponent.head,
+                theme.opponent.body,
+                theme.opponent.tail,
+                theme.ghost,
+                theme.food,
+                theme.wall,
+                theme.rock,
+                theme.portal,
+            ];
+            for glyph in glyphs.iter() {
+                assert_eq!(width(glyph), CELL_WIDTH, "{} in {}", glyph, theme.name);
+            }
+
+            let markers = [theme.selected, theme.item, theme.unlocked, theme.locked];
+            for marker in markers.iter() {
+                assert_eq!(
+                    width(marker),
+                    MARKER_WIDTH,
+                    "{:?} in {}",
+                    marker,
+                    theme.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn next() {
+        assert_eq!(EMOJI.next(), ASCII);
+        assert_eq!(BLOCKS.next(), EMOJI);
+    }
+
+    #[test]
+    fn ascii() {
+        assert!(format!("{:?}", ASCII).is_ascii());
+        assert_eq!(ASCII.logo("🐍🐍👅  🐍"), "oooo@@  oo");
+    }
+}
@@ -0,0 +1,84 @@
+//! Display widths, in terminal columns
+//!
+//! Emoji and other wide characters take two columns, so layouts are computed
+//! from the display width of strings rather than their length.
+
+use crate::physics::Point;
+use crossterm::cursor::MoveTo;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// The width of a cell of the `World`, in columns.
+///
+/// Cells are square-ish: terminal characters are about twice as high as wide.
+pub const CELL_WIDTH: u16 = 2;
+
+/// Returns the display width of `text`.
+pub fn width(text: &str) -> u16 {
+    text.width() as u16
+}
+
+/// Returns the display width of the widest of `lines`.
+pub fn max_width<'a>(lines: impl IntoIterator<Item = &'a str>) -> u16 {
+    lines.into_iter().map(width).max().unwrap_or(0)
+}
+
+/// Returns the longest start of `text` at most `columns` wide.
+pub fn truncate(text: &str, columns: u16) -> &str {
+    let mut used = 0;
+
+    for (index, c) in text.char_indices() {
+        used += c.width().unwrap_or(0);
+        if used > usize::from(columns) {
+            return &text[..index];
+        }
+    }
+
+    text
+}
+
+/// Returns where to draw something `inner` wide or high centered in `outer`.
+pub fn center(outer: u16, inner: u16) -> u16 {
+    outer.saturating_sub(inner) / 2
+}
+
+/// Returns the number of cells fitting in `columns`.
+pub fn cells(columns: u16) -> u16 {
+    columns / CELL_WIDTH
+}
+
+/// Moves the cursor to `cell`.
+pub fn move_to(cell: Point) -> MoveTo {
+    MoveTo((cell.x as u16).saturating_mul(CELL_WIDTH), cell.y as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn widths() {
+        assert_eq!(width("snake"), 5);
+        assert_eq!(width("🐍🐍👅"), 6);
+        assert_eq!(width("↲ ñ"), 3);
+        assert_eq!(max_width(vec!["ab", "🦀🦀", ""]), 4);
+        assert_eq!(max_width(vec![]), 0);
+    }
+
+    #[test]
+    fn truncate_wide() {
+        assert_eq!(truncate("snake", 3), "sna");
+        assert_eq!(truncate("🐍🐍👅", 3), "🐍");
+        assert_eq!(truncate("🐍🐍👅", 4), "🐍🐍");
+        assert_eq!(truncate("🐍", 10), "🐍");
+    }
+
+    #[test]
+    fn cells_and_columns() {
+        assert_eq!(center(80, 21), 29);
+        assert_eq!(center(10, 21), 0);
+        assert_eq!(cells(81), 40);
+        assert_eq!(move_to(Point::new(3.0, 4.0)), MoveTo(3 * CELL_WIDTH, 4));
+        assert_eq!(move_to(Point::new(40000.0, 4.0)), MoveTo(u16::MAX, 4));
+    }
+}